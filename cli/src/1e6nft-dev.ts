@@ -7,8 +7,8 @@ import { clusterApiUrl, Keypair, PublicKey } from "@solana/web3.js";
 import { upload } from "./commands/upload";
 import { mint } from "./commands/mint";
 import { changeColorInstruction } from "./../../client/src/actions/change_color";
-import { acceptOfferInstruction } from "./../../client/src/actions/accept_offer";
-import { changeOfferInstruction } from "./../../client/src/actions/change_offer";
+import { AcceptOfferArgs, acceptOfferInstruction } from "./../../client/src/actions/accept_offer";
+import { ChangeOfferArgs, changeOfferInstruction } from "./../../client/src/actions/change_offer";
 import { initSpaceMetadataInstruction } from "./../../client/src/actions/init_space_metadata";
 import { sendTransactionWithRetryWithKeypair } from "./helpers/transactions";
 import { getMetadata, loadWalletKey } from "./helpers/accounts";
@@ -354,10 +354,13 @@ programCommand("test-accept-offer")
 
     log.debug("Instructions for accept offer");
 
+    // no server needed, as the test listing is priced in SOL
     const ixs_accept = await acceptOfferInstruction(
+      null,
+      solConnection,
       walletKeyPair,
       base_address,
-      { x: sx, y: sy, price: 1_000_000_000, seller: bob, mint: spaceMint }
+      new AcceptOfferArgs({ x: sx, y: sy, price: 1_000_000_000, seller: bob, mint: spaceMint })
     );
 
     await sendTransactionWithRetryWithKeypair(
@@ -391,13 +394,13 @@ programCommand("test-change-offer")
 
     log.debug("Parsed arguments!");
 
-    const ixs = await changeOfferInstruction(walletKeyPair, base_address, {
+    const ixs = await changeOfferInstruction(walletKeyPair, base_address, new ChangeOfferArgs({
       mint: mint_address,
       x,
       y,
       price: 1_000_000_000,
       create: true,
-    });
+    }));
 
     log.debug("Instructions complete");
     await sendTransactionWithRetryWithKeypair(
@@ -2,9 +2,19 @@ import {PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY, TransactionInstruction,} f
 import BN from "bn.js";
 import {Schema, serialize} from "borsh";
 import {ASSOCIATED_TOKEN_PROGRAM_ID, Token, TOKEN_PROGRAM_ID,} from "@solana/spl-token";
-import {NEIGHBORHOOD_SIZE, NEIGHBORHOOD_METADATA_SEED, SPACE_METADATA_SEED, SPACE_PROGRAM_ID, SELL_DELEGATE_SEED,} from "../constants";
+import {
+  NEIGHBORHOOD_SIZE,
+  NEIGHBORHOOD_METADATA_SEED,
+  SPACE_METADATA_SEED,
+  SPACE_PROGRAM_ID,
+  SELL_DELEGATE_SEED,
+  LISTING_METADATA_SEED,
+  LISTING_VAULT_SEED,
+  FEE_VAULT_SEED,
+  METADATA_PROGRAM_ID,
+} from "../constants";
 import {correct_negative_serialization, twoscomplement_i2u} from "../utils/borsh";
-import { Server } from "ws";
+import {decodeMetadata} from "./metadata";
 
 export const ACCEPT_OFFER_INSTRUCTION_ID = 4;
 export class AcceptOfferInstructionData {
@@ -12,6 +22,7 @@ export class AcceptOfferInstructionData {
   x: number;
   y: number;
   price: BN;
+  referrer: PublicKey | null;
 
   static schema: Schema = new Map([
     [
@@ -23,6 +34,7 @@ export class AcceptOfferInstructionData {
           ["x", "u64"],
           ["y", "u64"],
           ["price", "u64"],
+          ["referrer", { kind: "option", type: "pubkey" }],
         ],
       },
     ],
@@ -32,19 +44,25 @@ export class AcceptOfferInstructionData {
     x: number;
     y: number;
     price: number;
+    referrer: PublicKey | null;
   }) {
     this.x = args.x;
     this.y = args.y;
     this.price = new BN(Math.floor(args.price));
+    this.referrer = args.referrer;
   }
 }
 
+// price is the most alice pays; paymentMint and vault must match the listing
 export class AcceptOfferArgs{
   x: number;
   y: number;
   mint: PublicKey;
   price: number;
   seller: PublicKey;
+  paymentMint?: PublicKey;
+  vault?: boolean;
+  referrer?: PublicKey | null;
 
   constructor(args: {
     x: number;
@@ -52,12 +70,18 @@ export class AcceptOfferArgs{
     mint: PublicKey;
     price: number;
     seller: PublicKey;
+    paymentMint?: PublicKey;
+    vault?: boolean;
+    referrer?: PublicKey | null;
   }) {
     this.x = args.x;
     this.y = args.y;
     this.mint = args.mint;
     this.price = args.price;
     this.seller = args.seller;
+    this.paymentMint = args.paymentMint ?? PublicKey.default;
+    this.vault = args.vault ?? false;
+    this.referrer = args.referrer ?? null;
   }
 }
 
@@ -70,6 +94,9 @@ export const acceptOfferInstruction = async (
 ) => {
 
   const {x, y, mint, price, seller} = change;
+  const paymentMint = change.paymentMint ?? PublicKey.default;
+  const vault = change.vault ?? false;
+  const referrer = change.referrer ?? null;
 
   const n_x = Math.floor(x / NEIGHBORHOOD_SIZE);
   const n_y = Math.floor(y / NEIGHBORHOOD_SIZE);
@@ -83,7 +110,15 @@ export const acceptOfferInstruction = async (
     SPACE_PROGRAM_ID
   );
 
-  const neighborhoodCreator = await server.getNeighborhoodCreator(connection, n_x, n_y);
+  const [feeVault,] = await PublicKey.findProgramAddress(
+    [
+      base.toBuffer(),
+      Buffer.from(FEE_VAULT_SEED),
+      Buffer.from(twoscomplement_i2u(n_x)),
+      Buffer.from(twoscomplement_i2u(n_y)),
+    ],
+    SPACE_PROGRAM_ID
+  );
 
   const space_x = twoscomplement_i2u(x);
   const space_y = twoscomplement_i2u(y);
@@ -100,7 +135,23 @@ export const acceptOfferInstruction = async (
 
   const [sell_delegate_account,] =
       await PublicKey.findProgramAddress(
-      [base.toBuffer(), Buffer.from(SELL_DELEGATE_SEED)],
+      [
+        base.toBuffer(),
+        Buffer.from(SELL_DELEGATE_SEED),
+        Buffer.from(space_x),
+        Buffer.from(space_y),
+      ],
+      SPACE_PROGRAM_ID
+    );
+
+  const [listing_metadata_account,] =
+      await PublicKey.findProgramAddress(
+      [
+        base.toBuffer(),
+        Buffer.from(LISTING_METADATA_SEED),
+        Buffer.from(space_x),
+        Buffer.from(space_y),
+      ],
       SPACE_PROGRAM_ID
     );
 
@@ -112,18 +163,34 @@ export const acceptOfferInstruction = async (
     false
   );
 
-  const seller_space_ATA = await Token.getAssociatedTokenAddress(
-    ASSOCIATED_TOKEN_PROGRAM_ID,
-    TOKEN_PROGRAM_ID,
-    mint,
-    seller,
-    false
-  );
+  // a vault listing holds the space in the listing vault instead of the seller's ATA
+  let seller_space_account: PublicKey;
+  if (vault) {
+    [seller_space_account,] = await PublicKey.findProgramAddress(
+      [
+        base.toBuffer(),
+        Buffer.from(LISTING_VAULT_SEED),
+        Buffer.from(space_x),
+        Buffer.from(space_y),
+      ],
+      SPACE_PROGRAM_ID
+    );
+  }
+  else {
+    seller_space_account = await Token.getAssociatedTokenAddress(
+      ASSOCIATED_TOKEN_PROGRAM_ID,
+      TOKEN_PROGRAM_ID,
+      mint,
+      seller,
+      false
+    );
+  }
 
   const args = new AcceptOfferInstructionData({
     x,
     y,
     price,
+    referrer,
   });
 
   const keys = [
@@ -138,7 +205,7 @@ export const acceptOfferInstruction = async (
       isWritable: false,
     },
     {
-      pubkey: neighborhoodCreator,
+      pubkey: feeVault,
       isSigner: false,
       isWritable: true,
     },
@@ -168,7 +235,7 @@ export const acceptOfferInstruction = async (
       isWritable: true,
     },
     {
-      pubkey: seller_space_ATA,
+      pubkey: seller_space_account,
       isSigner: false,
       isWritable: true,
     },
@@ -197,8 +264,121 @@ export const acceptOfferInstruction = async (
       isSigner: false,
       isWritable: false,
     },
+    {
+      pubkey: listing_metadata_account,
+      isSigner: false,
+      isWritable: true,
+    },
   ];
 
+  // listings priced in a token pay the seller, creators, referrer and neighborhood creator in their ATAs
+  const tokenPayment = !paymentMint.equals(PublicKey.default);
+  const paymentATA = (owner: PublicKey) => Token.getAssociatedTokenAddress(
+    ASSOCIATED_TOKEN_PROGRAM_ID,
+    TOKEN_PROGRAM_ID,
+    paymentMint,
+    owner,
+    false
+  );
+  if (tokenPayment) {
+    const neighborhoodCreator = await server.getNeighborhoodCreator(connection, n_x, n_y);
+    keys.push(
+      {
+        pubkey: paymentMint,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: await paymentATA(wallet.publicKey),
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: await paymentATA(seller),
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: neighborhoodCreator,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: await paymentATA(neighborhoodCreator),
+        isSigner: false,
+        isWritable: true,
+      },
+    );
+  }
+
+  // royalties go to every verified creator with a nonzero share, in metadata order
+  const [metaplexMetadata,] = await PublicKey.findProgramAddress(
+    [
+      Buffer.from("metadata"),
+      METADATA_PROGRAM_ID.toBuffer(),
+      mint.toBuffer(),
+    ],
+    METADATA_PROGRAM_ID
+  );
+  keys.push({
+    pubkey: metaplexMetadata,
+    isSigner: false,
+    isWritable: false,
+  });
+  const metaplexMetadataAccount = await connection.getAccountInfo(metaplexMetadata);
+  const creators = metaplexMetadataAccount === null ? [] : (decodeMetadata(metaplexMetadataAccount.data).data.creators ?? []);
+  for (let creator of creators) {
+    if (!creator.verified || creator.share === 0) {
+      continue;
+    }
+    const creatorKey = new PublicKey(creator.address);
+    if (tokenPayment) {
+      keys.push(
+        {
+          pubkey: await paymentATA(creatorKey),
+          isSigner: false,
+          isWritable: true,
+        },
+        {
+          pubkey: creatorKey,
+          isSigner: false,
+          isWritable: false,
+        },
+      );
+    }
+    else {
+      keys.push({
+        pubkey: creatorKey,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
+  }
+
+  if (referrer !== null) {
+    if (tokenPayment) {
+      keys.push(
+        {
+          pubkey: await paymentATA(referrer),
+          isSigner: false,
+          isWritable: true,
+        },
+        {
+          pubkey: referrer,
+          isSigner: false,
+          isWritable: false,
+        },
+      );
+    }
+    else {
+      keys.push({
+        pubkey: referrer,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
+  }
+
   let data = Buffer.from(serialize(AcceptOfferInstructionData.schema, args));
   // borsh JS sucks, need to be able to serialize negative numbers
   data = correct_negative_serialization(data, 1, 9, space_x);
@@ -233,9 +413,9 @@ export const acceptOfferInstructions = async (
       base,
       change,
     );
-    
+
     Ixs.push(Ix[0]);
   }
 
   return Ixs;
-};
\ No newline at end of file
+};
@@ -1,8 +1,14 @@
-import {PublicKey, TransactionInstruction,} from "@solana/web3.js";
+import {PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY, TransactionInstruction,} from "@solana/web3.js";
 import BN from "bn.js";
 import {Schema, serialize} from "borsh";
 import {ASSOCIATED_TOKEN_PROGRAM_ID, Token, TOKEN_PROGRAM_ID,} from "@solana/spl-token";
-import {SPACE_METADATA_SEED, SPACE_PROGRAM_ID, SELL_DELEGATE_SEED,} from "../constants";
+import {
+  SPACE_METADATA_SEED,
+  SPACE_PROGRAM_ID,
+  SELL_DELEGATE_SEED,
+  LISTING_METADATA_SEED,
+  LISTING_VAULT_SEED,
+} from "../constants";
 import {correct_negative_serialization, twoscomplement_i2u} from "../utils/borsh";
 
 export const CHANGE_OFFER_INSTRUCTION_ID = 3;
@@ -12,6 +18,13 @@ export class ChangeOfferInstructionData {
   y: number;
   price: BN;
   create: boolean;
+  end_price: BN;
+  start_timestamp: BN;
+  end_timestamp: BN;
+  payment_mint: PublicKey;
+  expires_at: BN;
+  buyer: PublicKey;
+  vault: boolean;
 
   static schema: Schema = new Map([
     [
@@ -24,6 +37,13 @@ export class ChangeOfferInstructionData {
           ["y", "u64"],
           ["price", "u64"],
           ["create", "u8"],
+          ["end_price", "u64"],
+          ["start_timestamp", "u64"],
+          ["end_timestamp", "u64"],
+          ["payment_mint", "pubkey"],
+          ["expires_at", "u64"],
+          ["buyer", "pubkey"],
+          ["vault", "u8"],
         ],
       },
     ],
@@ -34,32 +54,68 @@ export class ChangeOfferInstructionData {
     y: number;
     price: number;
     create: boolean;
+    end_price: number;
+    start_timestamp: number;
+    end_timestamp: number;
+    payment_mint: PublicKey;
+    expires_at: number;
+    buyer: PublicKey;
+    vault: boolean;
   }) {
     this.x = args.x;
     this.y = args.y;
     this.price = new BN(Math.floor(args.price));
     this.create = args.create;
+    this.end_price = new BN(Math.floor(args.end_price));
+    this.start_timestamp = new BN(args.start_timestamp);
+    this.end_timestamp = new BN(args.end_timestamp);
+    this.payment_mint = args.payment_mint;
+    this.expires_at = new BN(args.expires_at);
+    this.buyer = args.buyer;
+    this.vault = args.vault;
   }
 }
 
+// only x, y, mint, price and create are required, the rest default to a plain fixed price SOL listing
 export class ChangeOfferArgs{
   x: number;
   y: number;
   mint: PublicKey;
   price: number;
   create: boolean;
+  endPrice?: number;
+  startTimestamp?: number;
+  endTimestamp?: number;
+  paymentMint?: PublicKey;
+  expiresAt?: number;
+  buyer?: PublicKey;
+  vault?: boolean; // also set when cancelling a vault listing
   constructor(args: {
     x: number;
     y: number;
     mint: PublicKey;
     price: number;
     create: boolean;
+    endPrice?: number;
+    startTimestamp?: number;
+    endTimestamp?: number;
+    paymentMint?: PublicKey;
+    expiresAt?: number;
+    buyer?: PublicKey;
+    vault?: boolean;
   }) {
     this.x = args.x;
     this.y = args.y;
     this.mint = args.mint;
     this.price = args.price;
     this.create = args.create;
+    this.endPrice = args.endPrice ?? 0;
+    this.startTimestamp = args.startTimestamp ?? 0;
+    this.endTimestamp = args.endTimestamp ?? 0;
+    this.paymentMint = args.paymentMint ?? PublicKey.default;
+    this.expiresAt = args.expiresAt ?? 0;
+    this.buyer = args.buyer ?? PublicKey.default;
+    this.vault = args.vault ?? false;
   }
 }
 
@@ -69,6 +125,19 @@ export const changeOfferInstruction = async (
   change: ChangeOfferArgs,
 ) => {
   const {x, y, mint, price, create} = change;
+  const args = new ChangeOfferInstructionData({
+    x,
+    y,
+    price,
+    create,
+    end_price: change.endPrice ?? 0,
+    start_timestamp: change.startTimestamp ?? 0,
+    end_timestamp: change.endTimestamp ?? 0,
+    payment_mint: change.paymentMint ?? PublicKey.default,
+    expires_at: change.expiresAt ?? 0,
+    buyer: change.buyer ?? PublicKey.default,
+    vault: change.vault ?? false,
+  });
 
   const space_x = twoscomplement_i2u(x);
   const space_y = twoscomplement_i2u(y);
@@ -85,7 +154,23 @@ export const changeOfferInstruction = async (
 
   const [sell_delegate_account,] =
     await PublicKey.findProgramAddress(
-      [base.toBuffer(), Buffer.from(SELL_DELEGATE_SEED)],
+      [
+        base.toBuffer(),
+        Buffer.from(SELL_DELEGATE_SEED),
+        Buffer.from(space_x),
+        Buffer.from(space_y),
+      ],
+      SPACE_PROGRAM_ID
+    );
+
+  const [listing_metadata_account,] =
+    await PublicKey.findProgramAddress(
+      [
+        base.toBuffer(),
+        Buffer.from(LISTING_METADATA_SEED),
+        Buffer.from(space_x),
+        Buffer.from(space_y),
+      ],
       SPACE_PROGRAM_ID
     );
 
@@ -96,13 +181,6 @@ export const changeOfferInstruction = async (
     wallet.publicKey,
     false
   );
-  
-  const args = new ChangeOfferInstructionData({
-    x,
-    y,
-    price,
-    create,
-  });
 
   const keys = [
     {
@@ -118,7 +196,7 @@ export const changeOfferInstruction = async (
     {
       pubkey: wallet.publicKey,
       isSigner: true,
-      isWritable: false,
+      isWritable: true,
     },
     {
       pubkey: spaceATA,
@@ -135,14 +213,55 @@ export const changeOfferInstruction = async (
       isSigner: false,
       isWritable: false,
     },
+    {
+      pubkey: listing_metadata_account,
+      isSigner: false,
+      isWritable: true,
+    },
+    {
+      pubkey: SystemProgram.programId,
+      isSigner: false,
+      isWritable: false,
+    },
   ];
 
+  if (args.vault) {
+    const [listing_vault_account,] =
+      await PublicKey.findProgramAddress(
+        [
+          base.toBuffer(),
+          Buffer.from(LISTING_VAULT_SEED),
+          Buffer.from(space_x),
+          Buffer.from(space_y),
+        ],
+        SPACE_PROGRAM_ID
+      );
+
+    keys.push(
+      {
+        pubkey: listing_vault_account,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: mint,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: SYSVAR_RENT_PUBKEY,
+        isSigner: false,
+        isWritable: false,
+      },
+    );
+  }
+
   let data = Buffer.from(serialize(ChangeOfferInstructionData.schema, args));
   // borsh JS sucks, need to be able to serialize negative numbers
   data = correct_negative_serialization(data, 1, 9, space_x);
   data = correct_negative_serialization(data, 9, 17, space_y);
 
-  let Ix = 
+  let Ix =
     [new TransactionInstruction({
       keys,
       programId: SPACE_PROGRAM_ID,
@@ -167,7 +286,7 @@ export const changeOfferInstructions = async (
       base,
       change,
     );
-    
+
     Ixs.push(Ix[0]);
   }
 
@@ -17,6 +17,9 @@ export const NEIGHBORHOOD_FRAME_POINTER_SEED = "neighborhood_frame_pointer";
 export const NEIGHBORHOOD_METADATA_SEED = "neighborhood_metadata";
 export const SELL_DELEGATE_SEED = "sell_delegate"
 export const SPACE_METADATA_SEED = "space_metadata";
+export const LISTING_METADATA_SEED = "listing_metadata";
+export const LISTING_VAULT_SEED = "listing_vault";
+export const FEE_VAULT_SEED = "fee_vault";
 export const VOUCHER_MINT_SEED = "voucher_mint";
 export const VOUCHER_SINK_SEED = "voucher_sink";
 export const RENT_ACCOUNT_SEED = "rent_account";
@@ -35,6 +35,10 @@ pub enum CustomError {
     InvalidListing,
     #[error("ColorClusterAlreadyInitialized")]
     ColorClusterAlreadyInitialized,
+    #[error("Paused")]
+    Paused,
+    #[error("OutOfBounds")]
+    OutOfBounds,
 }
 
 impl From<CustomError> for ProgramError {
@@ -19,13 +19,14 @@ use crate::{
         SPACE_METADATA_SEED,
         NEIGHBORHOOD_FRAME_BASE_SEED,
         NEIGHBORHOOD_FRAME_POINTER_SEED,
+        Base,
         NeighborhoodMetadata,
         SpaceMetadata,
         NeighborhoodFrameBase,
         NeighborhoodFramePointer,
     },
-    processor::processor_utils::{get_neighborhood_xy},
-    validation_utils::{assert_is_ata, assert_keys_equal},
+    processor::processor_utils::{assert_not_paused, get_neighborhood_xy},
+    validation_utils::{assert_is_ata, assert_keys_equal, assert_owned_by},
 };
 
 pub fn process(
@@ -48,6 +49,11 @@ pub fn process(
         return Err(ProgramError::MissingRequiredSignature);
     }
 
+    // check world is not paused
+    assert_owned_by(base, &Pubkey::from_str(SPACE_PID).unwrap())?;
+    let base_data: Base = try_from_slice_unchecked(&base.data.borrow())?;
+    assert_not_paused(&base_data)?;
+
     //  deserialize and check all PDAs
     let neighborhood_frame_base_data: NeighborhoodFrameBase =
         try_from_slice_unchecked(&neighborhood_frame_base.data.borrow())?;
@@ -15,6 +15,7 @@ use std::str::FromStr;
 
 use crate::{
     instruction::InitFrameArgs,
+    processor::processor_utils::assert_in_world_bounds,
     state::{
         SPACE_PID,
         NEIGHBORHOOD_SIZE,
@@ -23,12 +24,13 @@ use crate::{
         NEIGHBORHOOD_FRAME_BASE_RESERVE,
         NEIGHBORHOOD_FRAME_POINTER_SEED,
         NEIGHBORHOOD_FRAME_POINTER_RESERVE,
+        Base,
         NeighborhoodMetadata,
         NeighborhoodFrameBase,
         NeighborhoodFramePointer,
         MAX_FRAMES,
     },
-    validation_utils::assert_keys_equal,
+    validation_utils::{assert_keys_equal, assert_owned_by},
 };
 
 pub fn process(
@@ -53,6 +55,11 @@ pub fn process(
     // check programs
     assert_keys_equal(system_program::id(), *system_program.key)?;
 
+    // check neighborhood is inside the world
+    assert_owned_by(base, &Pubkey::from_str(SPACE_PID).unwrap())?;
+    let base_data: Base = try_from_slice_unchecked(&base.data.borrow())?;
+    assert_in_world_bounds(&base_data, args.neighborhood_x, args.neighborhood_y)?;
+
     // check PDA of neighborhood frame base account and create it if necessary
     let seeds_neighborhood_frame_base = &[
         &base.key.to_bytes(),
@@ -1,4 +1,15 @@
-use crate::state::NEIGHBORHOOD_SIZE;
+use solana_program::{
+    entrypoint::ProgramResult,
+    msg,
+};
+use crate::{
+    error::CustomError,
+    state::{
+        MAX_NEIGHBORHOOD_COORDINATE,
+        NEIGHBORHOOD_SIZE,
+        Base,
+    },
+};
 
 pub fn floor_divide(x: i64, y: usize) -> i64 {
     if x >= 0{
@@ -15,4 +26,26 @@ pub fn floor_divide(x: i64, y: usize) -> i64 {
 
 pub fn get_neighborhood_xy(x: i64, y: i64) -> (i64, i64){
     return (floor_divide(x, NEIGHBORHOOD_SIZE), floor_divide(y, NEIGHBORHOOD_SIZE));
-}
\ No newline at end of file
+}
+
+// coloring refuses to run while the base authority has paused the world
+pub fn assert_not_paused(base_data: &Base) -> ProgramResult {
+    if base_data.paused {
+        msg!("Error: paused by base authority");
+        return Err(CustomError::Paused.into());
+    }
+    Ok(())
+}
+
+// reject neighborhoods outside the base world bounds or close enough to i64 extremes to overflow
+pub fn assert_in_world_bounds(base_data: &Base, neighborhood_x: i64, neighborhood_y: i64) -> ProgramResult {
+    let (min_x, min_y, max_x, max_y) = match base_data.world_bounds {
+        Some(bounds) => (bounds.min_x, bounds.min_y, bounds.max_x, bounds.max_y),
+        None => (-MAX_NEIGHBORHOOD_COORDINATE, -MAX_NEIGHBORHOOD_COORDINATE, MAX_NEIGHBORHOOD_COORDINATE, MAX_NEIGHBORHOOD_COORDINATE),
+    };
+    if neighborhood_x < min_x || neighborhood_x > max_x || neighborhood_y < min_y || neighborhood_y > max_y {
+        msg!("Error: neighborhood ({}, {}) is outside the world bounds", neighborhood_x, neighborhood_y);
+        return Err(CustomError::OutOfBounds.into());
+    }
+    Ok(())
+}
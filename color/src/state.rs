@@ -3,7 +3,6 @@ use solana_program::pubkey::Pubkey;
 use std::mem::size_of;
 
 pub const NEIGHBORHOOD_SIZE: usize = 200;
-pub const MARKETPLACE_FEE: f64 = 0.01;
 pub const EXTEND_TOKEN_MINT: &str = "PLACEHOLDER";
 pub const NEIGHBORHOOD_METADATA_SEED: &[u8] = b"neighborhood_metadata";
 pub const NEIGHBORHOOD_LIST_SEED: &[u8] = b"neighborhood_list";
@@ -13,15 +12,60 @@ pub const SPACE_METADATA_SEED: &[u8] = b"space_metadata";
 pub const SELL_DELEGATE_SEED: &[u8] = b"sell_delegate";
 
 pub const BASE_RESERVE: usize = 2048;
+pub const MAX_FREE_CREATORS: usize = 16;
 #[repr(C)]
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
 pub struct Base {
     pub neighborhood_count: u64,
     pub authority: Pubkey,
     pub authority_privileges: bool,
+    pub marketplace_fee_bps: Option<u16>,
+    pub max_neighborhood_fee_bps: Option<u16>,
+    pub referral_fee_bps: u16,
+    pub pending_authority: Option<Pubkey>,
+    pub admin_delay: i64,
+    pub admin_action_count: u64,
+    pub paused: bool,
+    pub free_neighborhoods: u64,
+    pub free_creators: Vec<Pubkey>,
+    pub neighborhood_pricing: Option<NeighborhoodPricing>,
+    pub require_adjacent: bool,
+    pub world_bounds: Option<WorldBounds>,
 }
 impl Base {
-    pub const LEN: usize = size_of::<u64>() + size_of::<Pubkey>() + size_of::<bool>();
+    pub const LEN: usize = size_of::<u64>() + size_of::<Pubkey>() + size_of::<bool>()
+        + size_of::<u8>() + size_of::<u16>() + size_of::<u8>() + size_of::<u16>() + size_of::<u16>()
+        + size_of::<u8>() + size_of::<Pubkey>() + size_of::<i64>() + size_of::<u64>()
+        + size_of::<bool>() + size_of::<u64>() + size_of::<u32>() + MAX_FREE_CREATORS * size_of::<Pubkey>()
+        + size_of::<u8>() + NeighborhoodPricing::LEN + size_of::<bool>() + size_of::<u8>() + WorldBounds::LEN;
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy)]
+pub struct NeighborhoodPricing {
+    pub base_price: u64,
+    pub ring_increment: u64,
+    pub free_radius: u64,
+    pub ring_cap: Option<u64>,
+}
+impl NeighborhoodPricing {
+    pub const LEN: usize = size_of::<u64>() + size_of::<u64>() + size_of::<u64>() + size_of::<u8>() + size_of::<u64>();
+}
+
+// neighborhood coordinates past this overflow space coordinates
+pub const MAX_NEIGHBORHOOD_COORDINATE: i64 = i64::MAX / NEIGHBORHOOD_SIZE as i64 - 1;
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy)]
+pub struct WorldBounds {
+    // inclusive neighborhood coordinates
+    pub min_x: i64,
+    pub min_y: i64,
+    pub max_x: i64,
+    pub max_y: i64,
+}
+impl WorldBounds {
+    pub const LEN: usize = size_of::<i64>() + size_of::<i64>() + size_of::<i64>() + size_of::<i64>();
 }
 
 pub const MAX_NEIGHBORHOODS: usize = 8;
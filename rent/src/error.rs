@@ -33,6 +33,8 @@ pub enum CustomError {
     NotSwappable,
     #[error("InvalidListing")]
     InvalidListing,
+    #[error("Paused")]
+    Paused,
 }
 
 impl From<CustomError> for ProgramError {
@@ -12,6 +12,7 @@ use crate::{
 
 pub mod set_rent;
 pub mod accept_rent;
+pub mod processor_utils;
 
 pub struct Processor;
 impl Processor {
@@ -20,14 +20,16 @@ use spl_token;
 use crate::{
     error::CustomError,
     instruction::AcceptRentArgs,
+    processor::processor_utils::assert_not_paused,
     state::{
+        Base,
         SPACE_METADATA_SEED,
         RENT_ACCOUNT_SEED,
         RentAccount,
         SpaceMetadata,
         SPACE_PID,
     },
-    validation_utils::{assert_is_ata, assert_keys_equal},
+    validation_utils::{assert_is_ata, assert_keys_equal, assert_owned_by},
 };
 
 pub fn process(
@@ -58,6 +60,11 @@ pub fn process(
     // check programs
     assert_keys_equal(system_program::id(), *system_program.key)?;
 
+    // check world is not paused
+    assert_owned_by(base, &Pubkey::from_str(SPACE_PID).unwrap())?;
+    let base_data: Base = try_from_slice_unchecked(&base.data.borrow())?;
+    assert_not_paused(&base_data)?;
+
 
     //deserialize and verify space metadata
     let space_metadata_data: SpaceMetadata = try_from_slice_unchecked(&space_metadata.data.borrow_mut())?;
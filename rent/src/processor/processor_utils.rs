@@ -0,0 +1,17 @@
+use solana_program::{
+    entrypoint::ProgramResult,
+    msg,
+};
+use crate::{
+    error::CustomError,
+    state::Base,
+};
+
+// renting refuses to run while the base authority has paused the world
+pub fn assert_not_paused(base_data: &Base) -> ProgramResult {
+    if base_data.paused {
+        msg!("Error: paused by base authority");
+        return Err(CustomError::Paused.into());
+    }
+    Ok(())
+}
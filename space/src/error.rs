@@ -33,6 +33,16 @@ pub enum CustomError {
     NotSwappable,
     #[error("InvalidListing")]
     InvalidListing,
+    #[error("NumericalOverflow")]
+    NumericalOverflow,
+    #[error("Timelocked")]
+    Timelocked,
+    #[error("Paused")]
+    Paused,
+    #[error("OutOfBounds")]
+    OutOfBounds,
+    #[error("InvalidReferrer")]
+    InvalidReferrer,
 }
 
 impl From<CustomError> for ProgramError {
@@ -1,5 +1,6 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::program_error::ProgramError;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use crate::state::{AdminAction, NeighborhoodPricing, WorldBounds};
 
 #[repr(C)]
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
@@ -30,6 +31,13 @@ pub struct ChangeOfferArgs {
     pub space_y: i64,
     pub price: u64,
     pub create: bool,
+    pub end_price: u64,
+    pub start_timestamp: i64,
+    pub end_timestamp: i64,
+    pub payment_mint: Pubkey,
+    pub expires_at: i64,
+    pub buyer: Pubkey,
+    pub vault: bool,
 }
 
 #[repr(C)]
@@ -37,7 +45,8 @@ pub struct ChangeOfferArgs {
 pub struct AcceptOfferArgs {
     pub space_x: i64,
     pub space_y: i64,
-    pub price: u64,
+    pub max_price: u64,
+    pub referrer: Option<Pubkey>,
 }
 
 #[repr(C)]
@@ -59,6 +68,274 @@ pub struct UpdateAuthorityArgs {
 
 }
 
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct PlaceBidArgs {
+    pub space_x: i64,
+    pub space_y: i64,
+    pub price: u64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct CancelBidArgs {
+    pub space_x: i64,
+    pub space_y: i64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct AcceptBidArgs {
+    pub space_x: i64,
+    pub space_y: i64,
+    pub price: u64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct PlaceNeighborhoodBidArgs {
+    pub neighborhood_x: i64,
+    pub neighborhood_y: i64,
+    pub price: u64,
+    pub quantity: u64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct CancelNeighborhoodBidArgs {
+    pub neighborhood_x: i64,
+    pub neighborhood_y: i64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct AcceptNeighborhoodBidArgs {
+    pub space_x: i64,
+    pub space_y: i64,
+    pub price: u64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct CreateAuctionArgs {
+    pub space_x: i64,
+    pub space_y: i64,
+    pub reserve_price: u64,
+    pub min_increment: u64,
+    pub duration: i64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct PlaceAuctionBidArgs {
+    pub space_x: i64,
+    pub space_y: i64,
+    pub amount: u64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct SettleAuctionArgs {
+    pub space_x: i64,
+    pub space_y: i64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct SetMarketplaceFeeArgs {
+    pub marketplace_fee_bps: u16,
+    pub max_neighborhood_fee_bps: u16,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct SetNeighborhoodFeeArgs {
+    pub neighborhood_x: i64,
+    pub neighborhood_y: i64,
+    pub marketplace_fee_bps: Option<u16>,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct ExpireOfferArgs {
+    pub space_x: i64,
+    pub space_y: i64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct SyncSpaceArgs {
+    pub space_x: i64,
+    pub space_y: i64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct ProposeSwapArgs {
+    pub offered_x: i64,
+    pub offered_y: i64,
+    pub wanted_x: i64,
+    pub wanted_y: i64,
+    pub proposer_top_up: u64,
+    pub acceptor_top_up: u64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct AcceptSwapArgs {
+    pub offered_x: i64,
+    pub offered_y: i64,
+    pub wanted_x: i64,
+    pub wanted_y: i64,
+    pub proposer_top_up: u64,
+    pub acceptor_top_up: u64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct CancelSwapArgs {
+    pub offered_x: i64,
+    pub offered_y: i64,
+    pub wanted_x: i64,
+    pub wanted_y: i64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct ChangeOffersArgs {
+    pub offers: Vec<ChangeOfferArgs>,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct AcceptOffersArgs {
+    pub offers: Vec<AcceptOfferArgs>,
+    pub max_total: u64,
+    pub skip_filled: bool,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct ListBundleArgs {
+    pub min_x: i64,
+    pub min_y: i64,
+    pub max_x: i64,
+    pub max_y: i64,
+    pub price: u64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct DelistBundleArgs {
+    pub min_x: i64,
+    pub min_y: i64,
+    pub max_x: i64,
+    pub max_y: i64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct AcceptBundleArgs {
+    pub min_x: i64,
+    pub min_y: i64,
+    pub max_x: i64,
+    pub max_y: i64,
+    pub max_price: u64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct SetReferralFeeArgs {
+    pub referral_fee_bps: u16,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct ClaimFeesArgs {
+    pub neighborhood_x: i64,
+    pub neighborhood_y: i64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct ProposeAuthorityArgs {
+
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct AcceptAuthorityArgs {
+
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct CancelAuthorityArgs {
+
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct QueueAdminActionArgs {
+    pub action: AdminAction,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct ExecuteAdminActionArgs {
+    pub id: u64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct CancelAdminActionArgs {
+    pub id: u64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct SetAdminDelayArgs {
+    pub admin_delay: i64,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct SetPausedArgs {
+    pub paused: bool,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct SetFreeNeighborhoodsArgs {
+    pub free_neighborhoods: u64,
+    pub free_creators: Vec<Pubkey>,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct SetNeighborhoodPricingArgs {
+    pub pricing: NeighborhoodPricing,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct SetRequireAdjacentArgs {
+    pub require_adjacent: bool,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct SetWorldBoundsArgs {
+    pub world_bounds: Option<WorldBounds>,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct CancelAuctionArgs {
+    pub space_x: i64,
+    pub space_y: i64,
+}
+
 #[repr(C)] // elim
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)] // elim
 pub struct TempAddxyArgs { // elim
@@ -99,6 +376,7 @@ pub enum SpaceInstruction {
     9. system program
     10. token program
     11. rent program
+    12. neighborhood metadata of an edge-adjacent neighborhood, if the base requires adjacency
 
     */
     InitNeighborhoodMetadata,
@@ -119,33 +397,59 @@ pub enum SpaceInstruction {
 
     /*
     Change sell offer
+    A nonzero end_timestamp makes a dutch listing, declining from price to end_price
+    between start_timestamp and end_timestamp
+    A payment_mint other than the default pubkey prices the listing in that SPL token
+    A nonzero expires_at makes the listing unfillable from that timestamp on
+    A buyer other than the default pubkey makes a private listing only that buyer can take
+    With vault set, the space moves into a listing vault until the offer is taken or cancelled
     Accounts expected:
     0. Base account
     1. [Writable] space account
     2. [Signer, Writable] owner
     3. token account
-    4. sell delegate
+    4. sell delegate for this space
     5. token program
+    6. [Writable] listing metadata
+    7. system program
+    if listing with vault, or cancelling a vault listing:
+    8. [Writable] listing vault
+    9. space mint
+    10. rent sysvar
     */
     ChangeOffer,
 
     /*
-    Bob has sell offer for B, Alice takes it
+    Bob has sell offer for B, Alice takes it at the current listing price, up to max_price
+    Token priced listings pay the marketplace fee straight to the neighborhood creator, so the fee vault is left untouched
     Accounts expected:
     0. Base account
     1. Neighborhood Metadata
-    2. [Writable] Neighborhood creator
+    2. [Writable] Neighborhood fee vault (unused if the listing is priced in an SPL token)
     3. space account
     4. B mint account
     5. [Signer] alice
     6. [Writable] alice ATA account for B
     7. bob
-    8. [Writable] bob ATA account for B
-    9. [Signer] sell delegate
+    8. [Writable] bob ATA account for B, or the listing vault for a vault listing
+    9. sell delegate for this space (unused for a vault listing)
     10. system program
     11. token program
     12. associated token program
     13. rent program
+    14. [Writable] listing metadata (must record bob and account 8 as the lister)
+    if the listing is priced in an SPL token:
+    15. payment mint
+    16. [Writable] alice ATA for payment mint
+    17. [Writable] bob ATA for payment mint
+    18. neighborhood creator
+    19. [Writable] neighborhood creator ATA for payment mint
+    then:
+    20. (or 15.) metaplex metadata of space
+    then for each verified metaplex creator with a nonzero share, in order:
+    21. (or 16.) [Writable] creator, or creator ATA for payment mint followed by the creator
+    if args name a referrer, last:
+    [Writable] referrer, or referrer ATA for payment mint followed by the referrer
     */
     AcceptOffer,
 
@@ -173,7 +477,7 @@ pub enum SpaceInstruction {
     InitVoucherSystem,
 
     /*
-    Revoke the price exempt status for initializing new neighborhoods for creator of base
+    Revoke all free neighborhoods and clear the free creator allow-list
     Accounts expected:
     0. [Writable] Base account
     1. [Signer] Revoker
@@ -181,14 +485,472 @@ pub enum SpaceInstruction {
     RevokeAuthorityPrivileges,
 
     /*
-    Change creator
+    Change creator, with both the current and new creator signing
     Accounts expected:
     0. [Writable] Base account
     1. [Signer] Current creator
-    2. New creator
+    2. [Signer] New creator
     */
     UpdateAuthority,
 
+    /*
+    Place or update a bid on a space, escrowing the bid in the bid account
+    Accounts expected:
+    0. Base account
+    1. space metadata
+    2. [Writable] bid account
+    3. [Signer, Writable] bidder
+    4. system program
+    */
+    PlaceBid,
+
+    /*
+    Cancel a bid and refund the escrow
+    Accounts expected:
+    0. Base account
+    1. [Writable] bid account
+    2. [Signer, Writable] bidder
+    */
+    CancelBid,
+
+    /*
+    Alice has bid on B, Bob (owner of B) accepts it
+    Accounts expected:
+    0. Base account
+    1. Neighborhood Metadata
+    2. [Writable] Neighborhood fee vault
+    3. [Writable] space account
+    4. B mint account
+    5. [Writable] bid account
+    6. [Writable] alice
+    7. [Writable] alice ATA account for B
+    8. [Signer, Writable] bob
+    9. [Writable] bob ATA account for B
+    10. system program
+    11. token program
+    12. associated token program
+    13. rent program
+    */
+    AcceptBid,
+
+    /*
+    Place or update a bid for up to quantity spaces in a neighborhood at a fixed price per space,
+    escrowing price * quantity in the neighborhood bid account
+    Accounts expected:
+    0. Base account
+    1. Neighborhood metadata
+    2. [Writable] neighborhood bid account
+    3. [Signer, Writable] bidder
+    4. system program
+    */
+    PlaceNeighborhoodBid,
+
+    /*
+    Cancel a neighborhood bid and refund the remaining escrow
+    Accounts expected:
+    0. Base account
+    1. [Writable] neighborhood bid account
+    2. [Signer, Writable] bidder
+    */
+    CancelNeighborhoodBid,
+
+    /*
+    Alice has a neighborhood bid, Bob fills it with a space B in that neighborhood
+    Accounts expected:
+    0. Base account
+    1. Neighborhood Metadata
+    2. [Writable] Neighborhood fee vault
+    3. [Writable] space account
+    4. B mint account
+    5. [Writable] neighborhood bid account
+    6. [Writable] alice
+    7. [Writable] alice ATA account for B
+    8. [Signer, Writable] bob
+    9. [Writable] bob ATA account for B
+    10. system program
+    11. token program
+    12. associated token program
+    13. rent program
+    */
+    AcceptNeighborhoodBid,
+
+    /*
+    Start an english auction for a space, moving the space into the auction vault
+    Accounts expected:
+    0. Base account
+    1. [Writable] space account
+    2. space mint account
+    3. [Writable] auction account
+    4. [Writable] auction vault
+    5. [Signer, Writable] seller
+    6. [Writable] seller ATA for space
+    7. system program
+    8. token program
+    9. rent program
+    */
+    CreateAuction,
+
+    /*
+    Outbid the current highest bid, escrowing the bid and refunding the previous highest bidder
+    Accounts expected:
+    0. Base account
+    1. [Writable] auction account
+    2. [Signer, Writable] bidder
+    3. [Writable] previous highest bidder (any account if there are no bids yet)
+    4. system program
+    */
+    PlaceAuctionBid,
+
+    /*
+    Settle an ended auction: the space goes to the highest bidder (or back to the seller if there
+    were no bids) and the seller is paid the highest bid minus the marketplace fee
+    Accounts expected:
+    0. Base account
+    1. Neighborhood Metadata
+    2. [Writable] Neighborhood fee vault
+    3. space mint account
+    4. [Writable] auction account
+    5. [Writable] auction vault
+    6. [Writable] seller
+    7. winner (highest bidder, or seller if there were no bids)
+    8. [Writable] winner ATA for space
+    9. [Signer, Writable] payer
+    10. system program
+    11. token program
+    12. associated token program
+    13. rent program
+    */
+    SettleAuction,
+
+    /*
+    Set the marketplace fee and the bound on neighborhood overrides, in basis points
+    Accounts expected:
+    0. [Writable] Base account
+    1. [Signer] authority
+    */
+    SetMarketplaceFee,
+
+    /*
+    Override the marketplace fee for a neighborhood, or clear the override with None
+    Accounts expected:
+    0. Base account
+    1. [Writable] Neighborhood metadata
+    2. [Signer] Neighborhood creator
+    */
+    SetNeighborhoodFee,
+
+    /*
+    Permissionless crank clearing a sell offer past its expiry
+    Only the owner can revoke the sell delegate on their token account, so the listing is voided
+    by zeroing its price, which AcceptOffer treats as unlisted
+    A space held in the listing vault is returned to the seller and the vault closed
+    Accounts expected:
+    0. Base account
+    1. [Writable] space account
+    2. [Writable] listing metadata
+    if the listing is in the listing vault:
+    3. [Signer, Writable] payer, funding the seller ATA if it does not exist
+    4. [Writable] seller
+    5. [Writable] seller ATA for the space
+    6. [Writable] listing vault
+    7. space mint
+    8. system program
+    9. token program
+    10. associated token program
+    11. rent sysvar
+    */
+    ExpireOffer,
+
+    /*
+    Permissionless crank clearing a sell offer whose lister no longer holds the space
+    Accounts expected:
+    0. Base account
+    1. [Writable] space account
+    2. [Writable] listing metadata
+    3. token account recorded in the listing
+    */
+    SyncSpace,
+
+    /*
+    Offer space A for space B, moving A into a swap vault, optionally with SOL from either side
+    Accounts expected:
+    0. Base account
+    1. [Writable] space account for A
+    2. space account for B
+    3. A mint account
+    4. [Writable] swap account
+    5. [Writable] swap vault
+    6. [Signer, Writable] proposer
+    7. [Writable] proposer ATA account for A
+    8. system program
+    9. token program
+    10. rent sysvar
+    11. [Writable] listing metadata for A
+    */
+    ProposeSwap,
+
+    /*
+    Owner of space B takes a swap, receiving A and any proposer top up for B and any acceptor top up
+    Accounts expected:
+    0. Base account
+    1. [Writable] space account for A
+    2. [Writable] space account for B
+    3. A mint account
+    4. B mint account
+    5. [Writable] swap account
+    6. [Writable] swap vault
+    7. [Writable] proposer
+    8. [Writable] proposer ATA account for B
+    9. [Signer, Writable] acceptor
+    10. [Writable] acceptor ATA account for A
+    11. [Writable] acceptor ATA account for B
+    12. system program
+    13. token program
+    14. associated token program
+    15. rent program
+    16. [Writable] listing metadata for A
+    17. [Writable] listing metadata for B
+    */
+    AcceptSwap,
+
+    /*
+    Proposer withdraws a swap, getting back space A and any escrowed top up
+    Accounts expected:
+    0. Base account
+    1. [Writable] swap account
+    2. [Writable] swap vault
+    3. [Signer, Writable] proposer
+    4. [Writable] proposer ATA account for A
+    5. token program
+    */
+    CancelSwap,
+
+    /*
+    Change sell offers for many spaces of one owner, each entry as in ChangeOffer
+    Accounts expected:
+    0. Base account
+    1. [Signer, Writable] owner
+    2. token program
+    3. system program
+    then for each entry of offers, in order:
+    4. [Writable] space account
+    5. token account
+    6. sell delegate for this space
+    7. [Writable] listing metadata
+    if listing with vault, or cancelling a vault listing:
+    8. [Writable] listing vault
+    9. space mint
+    10. rent sysvar
+    */
+    ChangeOffers,
+
+    /*
+    Alice takes several SOL priced sell offers, each as in AcceptOffer, paying at most max_total
+    With skip_filled, listings no longer available are passed over instead of failing
+    Accounts expected:
+    0. Base account
+    1. [Signer, Writable] alice
+    2. system program
+    3. token program
+    4. associated token program
+    5. rent program
+    then for each entry of offers, in order:
+    6. Neighborhood Metadata
+    7. [Writable] Neighborhood fee vault
+    8. [Writable] space account
+    9. mint account
+    10. [Writable] alice ATA account for the space
+    11. [Writable] bob
+    12. [Writable] bob ATA account for the space, or the listing vault for a vault listing
+    13. sell delegate
+    14. [Writable] listing metadata
+    15. metaplex metadata of space
+    then for each verified metaplex creator with a nonzero share, in order:
+    16. [Writable] creator
+    if the entry names a referrer, last:
+    [Writable] referrer
+    */
+    AcceptOffers,
+
+    /*
+    List every space of a rectangle within one neighborhood as a single bundle, approving the bundle as delegate
+    Accounts expected:
+    0. Base account
+    1. [Signer, Writable] seller
+    2. [Writable] bundle account
+    3. system program
+    4. token program
+    then for each space, row by row from (min_x, min_y):
+    5. [Writable] space account
+    6. [Writable] seller ATA account for the space
+    */
+    ListBundle,
+
+    /*
+    Withdraw a bundle, revoking the bundle delegate
+    Accounts expected:
+    0. Base account
+    1. [Signer, Writable] seller
+    2. [Writable] bundle account
+    3. token program
+    then for each space, row by row from (min_x, min_y):
+    4. [Writable] seller ATA account for the space
+    */
+    DelistBundle,
+
+    /*
+    Buyer takes a bundle, receiving every space in it or none, for at most max_price
+    Accounts expected:
+    0. Base account
+    1. Neighborhood Metadata
+    2. [Writable] Neighborhood fee vault
+    3. [Writable] bundle account
+    4. [Signer, Writable] buyer
+    5. [Writable] seller
+    6. system program
+    7. token program
+    8. associated token program
+    9. rent program
+    then for each space, row by row from (min_x, min_y):
+    10. [Writable] space account
+    11. space mint account
+    12. [Writable] seller ATA account for the space
+    13. [Writable] buyer ATA account for the space
+    */
+    AcceptBundle,
+
+    /*
+    Set the share of the marketplace fee paid to referrers of purchases
+    Accounts expected:
+    0. [Writable] Base account
+    1. [Signer] authority
+    */
+    SetReferralFee,
+
+    /*
+    Neighborhood creator withdraws the fees accrued in the neighborhood fee vault
+    Accounts expected:
+    0. Base account
+    1. Neighborhood Metadata
+    2. [Writable] Neighborhood fee vault
+    3. [Signer] Neighborhood creator
+    4. [Writable] destination
+    */
+    ClaimFees,
+
+    /*
+    Propose a new creator, who takes over once they accept
+    Accounts expected:
+    0. [Writable] Base account
+    1. [Signer] Current creator
+    2. New creator
+    */
+    ProposeAuthority,
+
+    /*
+    Proposed creator accepts and becomes creator
+    Accounts expected:
+    0. [Writable] Base account
+    1. [Signer] New creator
+    */
+    AcceptAuthority,
+
+    /*
+    Withdraw a creator proposal before it is accepted
+    Accounts expected:
+    0. [Writable] Base account
+    1. [Signer] Current creator
+    */
+    CancelAuthority,
+
+    /*
+    Queue an admin action, executable once the base admin delay has passed
+    Accounts expected:
+    0. [Writable] Base account
+    1. [Signer] Creator
+    2. [Writable] Queued admin action PDA
+    3. System program
+    */
+    QueueAdminAction,
+
+    /*
+    Apply a queued admin action after its delay
+    Accounts expected:
+    0. [Writable] Base account
+    1. [Signer] Creator
+    2. [Writable] Queued admin action PDA
+    */
+    ExecuteAdminAction,
+
+    /*
+    Drop a queued admin action without applying it
+    Accounts expected:
+    0. Base account
+    1. [Signer] Creator
+    2. [Writable] Queued admin action PDA
+    */
+    CancelAdminAction,
+
+    /*
+    Raise the admin delay, lowering it must be queued once a delay is set
+    Accounts expected:
+    0. [Writable] Base account
+    1. [Signer] Creator
+    */
+    SetAdminDelay,
+
+    /*
+    Pause or unpause listing, buying, renting and coloring, not subject to the admin delay
+    Accounts expected:
+    0. [Writable] Base account
+    1. [Signer] Creator
+    */
+    SetPaused,
+
+    /*
+    Set the number of free neighborhoods and the creators besides the authority allowed to use them
+    Accounts expected:
+    0. [Writable] Base account
+    1. [Signer] Creator
+    */
+    SetFreeNeighborhoods,
+
+    /*
+    Set the EXTEND price curve for creating neighborhoods
+    Accounts expected:
+    0. [Writable] Base account
+    1. [Signer] Creator
+    */
+    SetNeighborhoodPricing,
+
+    /*
+    Require new neighborhoods other than the origin to border an existing one
+    Accounts expected:
+    0. [Writable] Base account
+    1. [Signer] Creator
+    */
+    SetRequireAdjacent,
+
+    /*
+    Set the neighborhood coordinates that neighborhoods, spaces and frames can be created in
+    Accounts expected:
+    0. [Writable] Base account
+    1. [Signer] Creator
+    */
+    SetWorldBounds,
+
+    /*
+    Seller withdraws an auction nobody has bid on, getting back the space
+    Accounts expected:
+    0. Base account
+    1. [Writable] auction account
+    2. [Writable] auction vault
+    3. [Signer, Writable] seller
+    4. [Writable] seller ATA for space
+    5. token program
+    */
+    CancelAuction,
+
     TempAddxy, // elim
 
     ChangeNeighborhoodName, // elim
@@ -207,6 +969,42 @@ impl SpaceInstruction {
             7 => Self::UpdateAuthority,
             8 => Self::ChangeNeighborhoodName, // elim?
             9 => Self::TempAddxy, // elim
+            10 => Self::PlaceBid,
+            11 => Self::CancelBid,
+            12 => Self::AcceptBid,
+            13 => Self::PlaceNeighborhoodBid,
+            14 => Self::CancelNeighborhoodBid,
+            15 => Self::AcceptNeighborhoodBid,
+            16 => Self::CreateAuction,
+            17 => Self::PlaceAuctionBid,
+            18 => Self::SettleAuction,
+            19 => Self::SetMarketplaceFee,
+            20 => Self::SetNeighborhoodFee,
+            21 => Self::ExpireOffer,
+            22 => Self::SyncSpace,
+            24 => Self::ProposeSwap,
+            25 => Self::AcceptSwap,
+            26 => Self::CancelSwap,
+            27 => Self::ChangeOffers,
+            28 => Self::AcceptOffers,
+            29 => Self::ListBundle,
+            30 => Self::DelistBundle,
+            31 => Self::AcceptBundle,
+            32 => Self::SetReferralFee,
+            33 => Self::ClaimFees,
+            34 => Self::ProposeAuthority,
+            35 => Self::AcceptAuthority,
+            36 => Self::CancelAuthority,
+            37 => Self::QueueAdminAction,
+            38 => Self::ExecuteAdminAction,
+            39 => Self::CancelAdminAction,
+            40 => Self::SetAdminDelay,
+            41 => Self::SetPaused,
+            42 => Self::SetFreeNeighborhoods,
+            43 => Self::SetNeighborhoodPricing,
+            44 => Self::SetRequireAdjacent,
+            45 => Self::SetWorldBounds,
+            46 => Self::CancelAuction,
             _ => return Err(ProgramError::InvalidInstructionData),
         })
     }
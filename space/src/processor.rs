@@ -9,6 +9,16 @@ use crate::{
         AcceptOfferArgs, ChangeOfferArgs, SpaceInstruction, InitBaseArgs,
         InitSpaceMetadataArgs, InitNeighborhoodMetadataArgs, RevokeAuthorityPrivilegesArgs,
         InitVoucherSystemArgs, UpdateAuthorityArgs,
+        PlaceBidArgs, CancelBidArgs, AcceptBidArgs,
+        PlaceNeighborhoodBidArgs, CancelNeighborhoodBidArgs, AcceptNeighborhoodBidArgs,
+        CreateAuctionArgs, PlaceAuctionBidArgs, SettleAuctionArgs,
+        SetMarketplaceFeeArgs, SetNeighborhoodFeeArgs, ExpireOfferArgs,
+        SyncSpaceArgs, ProposeSwapArgs, AcceptSwapArgs, CancelSwapArgs,
+        ChangeOffersArgs, AcceptOffersArgs, ListBundleArgs, DelistBundleArgs, AcceptBundleArgs,
+        SetReferralFeeArgs, ClaimFeesArgs, ProposeAuthorityArgs, AcceptAuthorityArgs, CancelAuthorityArgs,
+        QueueAdminActionArgs, ExecuteAdminActionArgs, CancelAdminActionArgs, SetAdminDelayArgs,
+        SetPausedArgs, SetFreeNeighborhoodsArgs, SetNeighborhoodPricingArgs,
+        SetRequireAdjacentArgs, SetWorldBoundsArgs, CancelAuctionArgs,
         TempAddxyArgs, ChangeNeighborhoodNameArgs // elim
     },
 };
@@ -21,6 +31,42 @@ pub mod accept_offer;
 pub mod init_voucher_system;
 pub mod revoke_authority_privileges;
 pub mod update_authority;
+pub mod place_bid;
+pub mod cancel_bid;
+pub mod accept_bid;
+pub mod place_neighborhood_bid;
+pub mod cancel_neighborhood_bid;
+pub mod accept_neighborhood_bid;
+pub mod create_auction;
+pub mod place_auction_bid;
+pub mod settle_auction;
+pub mod set_marketplace_fee;
+pub mod set_neighborhood_fee;
+pub mod expire_offer;
+pub mod sync_space;
+pub mod propose_swap;
+pub mod accept_swap;
+pub mod cancel_swap;
+pub mod change_offers;
+pub mod accept_offers;
+pub mod list_bundle;
+pub mod delist_bundle;
+pub mod accept_bundle;
+pub mod set_referral_fee;
+pub mod claim_fees;
+pub mod propose_authority;
+pub mod accept_authority;
+pub mod cancel_authority;
+pub mod queue_admin_action;
+pub mod execute_admin_action;
+pub mod cancel_admin_action;
+pub mod set_admin_delay;
+pub mod set_paused;
+pub mod set_free_neighborhoods;
+pub mod set_neighborhood_pricing;
+pub mod set_require_adjacent;
+pub mod set_world_bounds;
+pub mod cancel_auction;
 pub mod temp_add_xy; // elim
 pub mod change_nbdhoodname; // elim
 
@@ -78,6 +124,186 @@ impl Processor {
                 msg!("Instruction: update authority");
                 update_authority::process(program_id, accounts, &args)
             }
+            SpaceInstruction::PlaceBid => {
+                let args = PlaceBidArgs::try_from_slice(rest)?;
+                msg!("Instruction: place bid");
+                place_bid::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::CancelBid => {
+                let args = CancelBidArgs::try_from_slice(rest)?;
+                msg!("Instruction: cancel bid");
+                cancel_bid::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::AcceptBid => {
+                let args = AcceptBidArgs::try_from_slice(rest)?;
+                msg!("Instruction: accept bid");
+                accept_bid::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::PlaceNeighborhoodBid => {
+                let args = PlaceNeighborhoodBidArgs::try_from_slice(rest)?;
+                msg!("Instruction: place neighborhood bid");
+                place_neighborhood_bid::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::CancelNeighborhoodBid => {
+                let args = CancelNeighborhoodBidArgs::try_from_slice(rest)?;
+                msg!("Instruction: cancel neighborhood bid");
+                cancel_neighborhood_bid::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::AcceptNeighborhoodBid => {
+                let args = AcceptNeighborhoodBidArgs::try_from_slice(rest)?;
+                msg!("Instruction: accept neighborhood bid");
+                accept_neighborhood_bid::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::CreateAuction => {
+                let args = CreateAuctionArgs::try_from_slice(rest)?;
+                msg!("Instruction: create auction");
+                create_auction::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::PlaceAuctionBid => {
+                let args = PlaceAuctionBidArgs::try_from_slice(rest)?;
+                msg!("Instruction: place auction bid");
+                place_auction_bid::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::SettleAuction => {
+                let args = SettleAuctionArgs::try_from_slice(rest)?;
+                msg!("Instruction: settle auction");
+                settle_auction::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::SetMarketplaceFee => {
+                let args = SetMarketplaceFeeArgs::try_from_slice(rest)?;
+                msg!("Instruction: set marketplace fee");
+                set_marketplace_fee::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::SetNeighborhoodFee => {
+                let args = SetNeighborhoodFeeArgs::try_from_slice(rest)?;
+                msg!("Instruction: set neighborhood fee");
+                set_neighborhood_fee::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::ExpireOffer => {
+                let args = ExpireOfferArgs::try_from_slice(rest)?;
+                msg!("Instruction: expire offer");
+                expire_offer::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::SyncSpace => {
+                let args = SyncSpaceArgs::try_from_slice(rest)?;
+                msg!("Instruction: sync space");
+                sync_space::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::ProposeSwap => {
+                let args = ProposeSwapArgs::try_from_slice(rest)?;
+                msg!("Instruction: propose swap");
+                propose_swap::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::AcceptSwap => {
+                let args = AcceptSwapArgs::try_from_slice(rest)?;
+                msg!("Instruction: accept swap");
+                accept_swap::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::CancelSwap => {
+                let args = CancelSwapArgs::try_from_slice(rest)?;
+                msg!("Instruction: cancel swap");
+                cancel_swap::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::ChangeOffers => {
+                let args = ChangeOffersArgs::try_from_slice(rest)?;
+                msg!("Instruction: change offers");
+                change_offers::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::AcceptOffers => {
+                let args = AcceptOffersArgs::try_from_slice(rest)?;
+                msg!("Instruction: accept offers");
+                accept_offers::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::ListBundle => {
+                let args = ListBundleArgs::try_from_slice(rest)?;
+                msg!("Instruction: list bundle");
+                list_bundle::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::DelistBundle => {
+                let args = DelistBundleArgs::try_from_slice(rest)?;
+                msg!("Instruction: delist bundle");
+                delist_bundle::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::AcceptBundle => {
+                let args = AcceptBundleArgs::try_from_slice(rest)?;
+                msg!("Instruction: accept bundle");
+                accept_bundle::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::SetReferralFee => {
+                let args = SetReferralFeeArgs::try_from_slice(rest)?;
+                msg!("Instruction: set referral fee");
+                set_referral_fee::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::ClaimFees => {
+                let args = ClaimFeesArgs::try_from_slice(rest)?;
+                msg!("Instruction: claim fees");
+                claim_fees::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::ProposeAuthority => {
+                let args = ProposeAuthorityArgs::try_from_slice(rest)?;
+                msg!("Instruction: propose authority");
+                propose_authority::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::AcceptAuthority => {
+                let args = AcceptAuthorityArgs::try_from_slice(rest)?;
+                msg!("Instruction: accept authority");
+                accept_authority::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::CancelAuthority => {
+                let args = CancelAuthorityArgs::try_from_slice(rest)?;
+                msg!("Instruction: cancel authority");
+                cancel_authority::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::QueueAdminAction => {
+                let args = QueueAdminActionArgs::try_from_slice(rest)?;
+                msg!("Instruction: queue admin action");
+                queue_admin_action::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::ExecuteAdminAction => {
+                let args = ExecuteAdminActionArgs::try_from_slice(rest)?;
+                msg!("Instruction: execute admin action");
+                execute_admin_action::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::CancelAdminAction => {
+                let args = CancelAdminActionArgs::try_from_slice(rest)?;
+                msg!("Instruction: cancel admin action");
+                cancel_admin_action::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::SetAdminDelay => {
+                let args = SetAdminDelayArgs::try_from_slice(rest)?;
+                msg!("Instruction: set admin delay");
+                set_admin_delay::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::SetPaused => {
+                let args = SetPausedArgs::try_from_slice(rest)?;
+                msg!("Instruction: set paused");
+                set_paused::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::SetFreeNeighborhoods => {
+                let args = SetFreeNeighborhoodsArgs::try_from_slice(rest)?;
+                msg!("Instruction: set free neighborhoods");
+                set_free_neighborhoods::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::SetNeighborhoodPricing => {
+                let args = SetNeighborhoodPricingArgs::try_from_slice(rest)?;
+                msg!("Instruction: set neighborhood pricing");
+                set_neighborhood_pricing::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::SetRequireAdjacent => {
+                let args = SetRequireAdjacentArgs::try_from_slice(rest)?;
+                msg!("Instruction: set require adjacent");
+                set_require_adjacent::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::SetWorldBounds => {
+                let args = SetWorldBoundsArgs::try_from_slice(rest)?;
+                msg!("Instruction: set world bounds");
+                set_world_bounds::process(program_id, accounts, &args)
+            }
+            SpaceInstruction::CancelAuction => {
+                let args = CancelAuctionArgs::try_from_slice(rest)?;
+                msg!("Instruction: cancel auction");
+                cancel_auction::process(program_id, accounts, &args)
+            }
 
             
             SpaceInstruction::ChangeNeighborhoodName => { // elim
@@ -0,0 +1,47 @@
+use borsh::{BorshSerialize};
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    borsh::try_from_slice_unchecked,
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    program_error::ProgramError,
+    msg,
+};
+
+use crate::{
+    error::CustomError,
+    instruction::AcceptAuthorityArgs,
+    state::Base,
+    validation_utils::assert_owned_by,
+};
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _args: &AcceptAuthorityArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let base = next_account_info(account_info_iter)?;
+    let new_creator = next_account_info(account_info_iter)?;
+
+    assert_owned_by(base, program_id)?;
+    let mut base_data: Base = try_from_slice_unchecked(&base.data.borrow_mut())?;
+
+    if !new_creator.is_signer {
+        msg!("Error: Missing signer");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // check that signer was proposed
+    if base_data.pending_authority != Some(*new_creator.key) {
+        msg!("Error: signer is not the proposed authority");
+        return Err(CustomError::WrongAuthority.into());
+    }
+
+    // write to base
+    base_data.authority = *new_creator.key;
+    base_data.pending_authority = None;
+    base_data.serialize(&mut *base.data.borrow_mut())?;
+
+    Ok(())
+}
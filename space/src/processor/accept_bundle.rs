@@ -0,0 +1,204 @@
+use borsh::{BorshSerialize};
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    borsh::try_from_slice_unchecked,
+    entrypoint::ProgramResult,
+    msg,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    system_instruction, system_program,
+    sysvar::rent,
+};
+use spl_associated_token_account;
+use spl_token;
+
+use crate::{
+    error::CustomError,
+    instruction::AcceptBundleArgs,
+    processor::processor_utils::{assert_not_paused, clear_listing, close_account, create_ata_if_needed, create_fee_vault_if_needed, get_bundle_spaces, get_marketplace_fee, get_marketplace_fee_bps, get_neighborhood_xy, record_fee},
+    state::{
+        BUNDLE_SEED,
+        NEIGHBORHOOD_METADATA_SEED,
+        SPACE_METADATA_SEED,
+        Base,
+        Bundle,
+        NeighborhoodMetadata,
+        SpaceMetadata,
+    },
+    validation_utils::{assert_is_ata, assert_keys_equal, assert_owned_by},
+};
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &AcceptBundleArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let base = next_account_info(account_info_iter)?;
+    let neighborhood_metadata = next_account_info(account_info_iter)?;
+    let fee_vault = next_account_info(account_info_iter)?;
+    let bundle = next_account_info(account_info_iter)?;
+    let buyer = next_account_info(account_info_iter)?;
+    let seller = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
+    let token_program = next_account_info(account_info_iter)?;
+    let associated_token_program = next_account_info(account_info_iter)?;
+    let rent_program = next_account_info(account_info_iter)?;
+
+    if !buyer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // check programs
+    assert_keys_equal(system_program::id(), *system_program.key)?;
+    assert_keys_equal(spl_token::id(), *token_program.key)?;
+    assert_keys_equal(spl_associated_token_account::id(), *associated_token_program.key)?;
+    assert_keys_equal(rent::id(), *rent_program.key)?;
+
+    // deserialize base
+    assert_owned_by(base, program_id)?;
+    let base_data: Base = try_from_slice_unchecked(&base.data.borrow())?;
+    assert_not_paused(&base_data)?;
+
+    // deserialize and verify bundle account
+    let bundle_data: Bundle = try_from_slice_unchecked(&bundle.data.borrow())?;
+    let seeds_bundle = &[
+        &base.key.to_bytes(),
+        BUNDLE_SEED,
+        &args.min_x.to_le_bytes(),
+        &args.min_y.to_le_bytes(),
+        &args.max_x.to_le_bytes(),
+        &args.max_y.to_le_bytes(),
+        &[bundle_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_bundle, program_id)?;
+    assert_keys_equal(key, *bundle.key)?;
+    assert_keys_equal(bundle_data.seller, *seller.key)?;
+
+    // check bundle price is within what the user accepts
+    if bundle_data.price > args.max_price {
+        msg!("bundle price is {}", bundle_data.price);
+        msg!("args max price is {}", args.max_price);
+        msg!("Error: bundle has changed");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // deserialize and verify neighborhood metadata, which holds the whole bundle
+    let neighborhood_metadata_data: NeighborhoodMetadata = try_from_slice_unchecked(&neighborhood_metadata.data.borrow())?;
+    let (neighborhood_x, neighborhood_y) = get_neighborhood_xy(args.min_x, args.min_y);
+    let seeds_neighborhood_metadata = &[
+        &base.key.to_bytes(),
+        NEIGHBORHOOD_METADATA_SEED,
+        &neighborhood_x.to_le_bytes(),
+        &neighborhood_y.to_le_bytes(),
+        &[neighborhood_metadata_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_neighborhood_metadata, program_id)?;
+    assert_keys_equal(key, *neighborhood_metadata.key)?;
+
+    // create fee vault if necessary
+    create_fee_vault_if_needed(program_id, base, fee_vault, buyer, system_program, neighborhood_x, neighborhood_y)?;
+
+    // move every space, failing the whole purchase if any is no longer in the bundle
+    let spaces = get_bundle_spaces(args.min_x, args.min_y, args.max_x, args.max_y)?;
+    for (space_x, space_y) in spaces {
+        let space_metadata = next_account_info(account_info_iter)?;
+        let space_mint = next_account_info(account_info_iter)?;
+        let seller_ata_space = next_account_info(account_info_iter)?;
+        let buyer_ata_space = next_account_info(account_info_iter)?;
+
+        // deserialize and verify space metadata
+        let mut space_metadata_data: SpaceMetadata = try_from_slice_unchecked(&space_metadata.data.borrow())?;
+
+        if space_metadata_data.mint != *space_mint.key {
+            msg!("Error: space account does not match mint account");
+            return Err(CustomError::MintMismatch.into());
+        }
+
+        let seeds_space_metadata = &[
+            &base.key.to_bytes(),
+            SPACE_METADATA_SEED,
+            &space_x.to_le_bytes(),
+            &space_y.to_le_bytes(),
+            &[space_metadata_data.bump],
+        ];
+        let key = Pubkey::create_program_address(seeds_space_metadata, program_id)?;
+        assert_keys_equal(key, *space_metadata.key)?;
+
+        // create ATA if necessary
+        create_ata_if_needed(buyer, buyer, space_mint, buyer_ata_space, system_program, token_program, rent_program, associated_token_program)?;
+
+        // check ATAs
+        assert_is_ata(seller_ata_space, seller.key, space_mint.key)?;
+        assert_is_ata(buyer_ata_space, buyer.key, space_mint.key)?;
+
+        // check space is still held by the seller for the bundle
+        let seller_ata_space_data = spl_token::state::Account::unpack(&seller_ata_space.data.borrow())?;
+        if seller_ata_space_data.amount != 1 || !seller_ata_space_data.delegate.contains(bundle.key) {
+            msg!("Error: space ({}, {}) is no longer in the bundle", space_x, space_y);
+            return Err(CustomError::InvalidListing.into());
+        }
+
+        // transfer NFT from seller to buyer
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program.key,
+                seller_ata_space.key,
+                buyer_ata_space.key,
+                bundle.key,
+                &[],
+                1,
+            )?,
+            &[
+                token_program.clone(),
+                seller_ata_space.clone(),
+                buyer_ata_space.clone(),
+                bundle.clone(),
+            ],
+            &[seeds_bundle],
+        )?;
+
+        // the space changed hands, so any sell offer is void
+        clear_listing(&mut space_metadata_data);
+        space_metadata_data.serialize(&mut *space_metadata.data.borrow_mut())?;
+    }
+
+    let marketplace_fee = get_marketplace_fee(bundle_data.price, get_marketplace_fee_bps(&base_data, &neighborhood_metadata_data))?;
+
+    // transfer SOL from buyer to seller
+    invoke(
+        &system_instruction::transfer(
+            buyer.key,
+            seller.key,
+            bundle_data.price - marketplace_fee,
+        ),
+        &[
+            buyer.clone(),
+            seller.clone(),
+            system_program.clone(),
+        ],
+    )?;
+
+    // transfer marketplace fee to neighborhood fee vault
+    invoke(
+        &system_instruction::transfer(
+            buyer.key,
+            fee_vault.key,
+            marketplace_fee,
+        ),
+        &[
+            buyer.clone(),
+            fee_vault.clone(),
+            system_program.clone(),
+        ],
+    )?;
+    record_fee(fee_vault, marketplace_fee)?;
+
+    // return bundle account rent to seller
+    close_account(bundle, seller)?;
+
+    msg!("done!");
+    Ok(())
+}
@@ -0,0 +1,183 @@
+use borsh::{BorshSerialize};
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    borsh::try_from_slice_unchecked,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    system_program,
+    sysvar::{rent},
+};
+use spl_associated_token_account;
+use spl_token;
+
+use crate::{
+    error::CustomError,
+    instruction::AcceptNeighborhoodBidArgs,
+    processor::processor_utils::{assert_not_paused, clear_listing, close_account, create_ata_if_needed, create_fee_vault_if_needed, get_marketplace_fee, get_marketplace_fee_bps, get_neighborhood_xy, record_fee, transfer_lamports},
+    state::{
+        NEIGHBORHOOD_BID_SEED,
+        NEIGHBORHOOD_METADATA_SEED,
+        SPACE_METADATA_SEED,
+        Base,
+        NeighborhoodBid,
+        NeighborhoodMetadata,
+        SpaceMetadata,
+    },
+    validation_utils::{assert_is_ata, assert_keys_equal, assert_owned_by},
+};
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &AcceptNeighborhoodBidArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let base = next_account_info(account_info_iter)?;
+    let neighborhood_metadata = next_account_info(account_info_iter)?;
+    let fee_vault = next_account_info(account_info_iter)?;
+    let space_metadata = next_account_info(account_info_iter)?;
+    let space_mint = next_account_info(account_info_iter)?;
+    let neighborhood_bid = next_account_info(account_info_iter)?;
+    let bidder = next_account_info(account_info_iter)?;
+    let bidder_ata_space = next_account_info(account_info_iter)?;
+    let owner = next_account_info(account_info_iter)?;
+    let owner_ata_space = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
+    let token_program = next_account_info(account_info_iter)?;
+    let associated_token_program = next_account_info(account_info_iter)?;
+    let rent_program = next_account_info(account_info_iter)?;
+
+    if !owner.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // check programs
+    assert_keys_equal(system_program::id(), *system_program.key)?;
+    assert_keys_equal(spl_token::id(), *token_program.key)?;
+    assert_keys_equal(spl_associated_token_account::id(), *associated_token_program.key)?;
+    assert_keys_equal(rent::id(), *rent_program.key)?;
+
+    // deserialize base
+    assert_owned_by(base, program_id)?;
+    let base_data: Base = try_from_slice_unchecked(&base.data.borrow())?;
+    assert_not_paused(&base_data)?;
+
+    // deserialize and verify space metadata
+    let mut space_metadata_data: SpaceMetadata = try_from_slice_unchecked(&space_metadata.data.borrow_mut())?;
+
+    if space_metadata_data.mint != *space_mint.key {
+        msg!("Error: space account does not match mint account");
+        return Err(CustomError::MintMismatch.into());
+    }
+
+    let seeds_space_metadata = &[
+        &base.key.to_bytes(),
+        SPACE_METADATA_SEED,
+        &args.space_x.to_le_bytes(),
+        &args.space_y.to_le_bytes(),
+        &[space_metadata_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_space_metadata, program_id)?;
+    assert_keys_equal(key, *space_metadata.key)?;
+
+    // deserialize and verify neighborhood metadata
+    let neighborhood_metadata_data: NeighborhoodMetadata = try_from_slice_unchecked(&neighborhood_metadata.data.borrow())?;
+    let (neighborhood_x, neighborhood_y) = get_neighborhood_xy(args.space_x, args.space_y);
+    let seeds_neighborhood_metadata = &[
+        &base.key.to_bytes(),
+        NEIGHBORHOOD_METADATA_SEED,
+        &neighborhood_x.to_le_bytes(),
+        &neighborhood_y.to_le_bytes(),
+        &[neighborhood_metadata_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_neighborhood_metadata, program_id)?;
+    assert_keys_equal(key, *neighborhood_metadata.key)?;
+
+    // create fee vault if necessary
+    create_fee_vault_if_needed(program_id, base, fee_vault, owner, system_program, neighborhood_x, neighborhood_y)?;
+
+    // deserialize and verify neighborhood bid account, which must be for this space's neighborhood
+    let mut neighborhood_bid_data: NeighborhoodBid = try_from_slice_unchecked(&neighborhood_bid.data.borrow())?;
+    let seeds_neighborhood_bid = &[
+        &base.key.to_bytes(),
+        NEIGHBORHOOD_BID_SEED,
+        &neighborhood_x.to_le_bytes(),
+        &neighborhood_y.to_le_bytes(),
+        &bidder.key.to_bytes(),
+        &[neighborhood_bid_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_neighborhood_bid, program_id)?;
+    assert_keys_equal(key, *neighborhood_bid.key)?;
+    assert_keys_equal(neighborhood_bid_data.bidder, *bidder.key)?;
+
+    // check owner price equals bid price
+    if neighborhood_bid_data.price != args.price {
+        msg!("bid price is {}", neighborhood_bid_data.price);
+        msg!("args price is {}", args.price);
+        msg!("Error: bid has changed");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    if neighborhood_bid_data.quantity == 0 {
+        msg!("Error: bid already filled");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // create bidder ATA if necessary
+    create_ata_if_needed(owner, bidder, space_mint, bidder_ata_space, system_program, token_program, rent_program, associated_token_program)?;
+
+    // check ATAs
+    assert_is_ata(bidder_ata_space, bidder.key, space_mint.key)?;
+    assert_is_ata(owner_ata_space, owner.key, space_mint.key)?;
+
+    // check NFT owned
+    let owner_ata_space_data = spl_token::state::Account::unpack(&owner_ata_space.data.borrow())?;
+    if owner_ata_space_data.amount != 1 {
+        msg!("Error: token account does not own token");
+        return Err(CustomError::MissingTokenOwner.into());
+    }
+
+    // transfer NFT from owner to bidder
+    invoke(
+        &spl_token::instruction::transfer(
+            token_program.key,
+            owner_ata_space.key,
+            bidder_ata_space.key,
+            owner.key,
+            &[],
+            1,
+        )?,
+        &[
+            token_program.clone(),
+            owner_ata_space.clone(),
+            bidder_ata_space.clone(),
+            owner.clone(),
+        ],
+    )?;
+
+    // pay owner and neighborhood fee vault out of escrow
+    let marketplace_fee = get_marketplace_fee(neighborhood_bid_data.price, get_marketplace_fee_bps(&base_data, &neighborhood_metadata_data))?;
+    transfer_lamports(neighborhood_bid, owner, neighborhood_bid_data.price - marketplace_fee)?;
+    transfer_lamports(neighborhood_bid, fee_vault, marketplace_fee)?;
+    record_fee(fee_vault, marketplace_fee)?;
+
+    // close the bid once every space has been filled, returning rent to bidder
+    neighborhood_bid_data.quantity -= 1;
+    if neighborhood_bid_data.quantity == 0 {
+        close_account(neighborhood_bid, bidder)?;
+    }
+    else {
+        neighborhood_bid_data.serialize(&mut *neighborhood_bid.data.borrow_mut())?;
+    }
+
+    // the space changed hands, so any sell offer is void
+    clear_listing(&mut space_metadata_data);
+    space_metadata_data.serialize(&mut *space_metadata.data.borrow_mut())?;
+
+    msg!("done!");
+    Ok(())
+}
@@ -3,14 +3,14 @@ use solana_program::{
     account_info::{AccountInfo, next_account_info},
     entrypoint::ProgramResult,
     borsh::try_from_slice_unchecked,
+    clock::Clock,
     msg,
-    program::{invoke, invoke_signed},
+    program::invoke_signed,
     program_error::ProgramError,
     program_pack::Pack,
     pubkey::Pubkey,
-    system_instruction,
     system_program,
-    sysvar::{rent},
+    sysvar::{Sysvar, rent},
 };
 use spl_associated_token_account;
 use spl_token;
@@ -19,15 +19,18 @@ use crate::{
     error::CustomError,
     instruction::AcceptOfferArgs,
     state::{
-        MARKETPLACE_FEE,
+        LISTING_METADATA_SEED,
+        LISTING_VAULT_SEED,
         NEIGHBORHOOD_METADATA_SEED,
         SPACE_METADATA_SEED,
         SELL_DELEGATE_SEED,
+        Base,
+        ListingMetadata,
         NeighborhoodMetadata,
         SpaceMetadata,
     },
-    validation_utils::{assert_is_ata, assert_keys_equal},
-    processor::processor_utils::{get_neighborhood_xy},
+    validation_utils::{assert_is_ata, assert_keys_equal, assert_owned_by},
+    processor::processor_utils::{assert_not_paused, assert_valid_referrer, clear_listing, clear_listing_metadata, create_ata_if_needed, create_fee_vault_if_needed, get_listing_price, get_marketplace_fee, get_marketplace_fee_bps, get_neighborhood_xy, get_referral_fee, get_royalties, record_fee, transfer_payment},
 };
 
 pub fn process(
@@ -38,7 +41,7 @@ pub fn process(
     let account_info_iter = &mut accounts.iter();
     let base = next_account_info(account_info_iter)?;
     let neighborhood_metadata = next_account_info(account_info_iter)?;
-    let neighborhood_creator = next_account_info(account_info_iter)?;
+    let fee_vault = next_account_info(account_info_iter)?;
     let space_metadata = next_account_info(account_info_iter)?;
     let space_mint = next_account_info(account_info_iter)?;
     let alice = next_account_info(account_info_iter)?;
@@ -50,6 +53,11 @@ pub fn process(
     let token_program = next_account_info(account_info_iter)?;
     let associated_token_program = next_account_info(account_info_iter)?;
     let rent_program = next_account_info(account_info_iter)?;
+    let listing_metadata = next_account_info(account_info_iter)?;
+
+    if !alice.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
 
     // check programs
     assert_keys_equal(system_program::id(), *system_program.key)?;
@@ -57,6 +65,162 @@ pub fn process(
     assert_keys_equal(spl_associated_token_account::id(), *associated_token_program.key)?;
     assert_keys_equal(rent::id(), *rent_program.key)?;
 
+    // deserialize base
+    assert_owned_by(base, program_id)?;
+    let base_data: Base = try_from_slice_unchecked(&base.data.borrow())?;
+
+    assert_valid_referrer(&args.referrer, alice.key, bob.key)?;
+
+    let (price, marketplace_fee, listing_payment_mint) = take_offer(
+        program_id,
+        base,
+        &base_data,
+        neighborhood_metadata,
+        space_metadata,
+        space_mint,
+        alice,
+        alice_ata_space,
+        bob,
+        bob_ata_space,
+        sell_delegate,
+        system_program,
+        token_program,
+        associated_token_program,
+        rent_program,
+        listing_metadata,
+        args,
+        false,
+        false,
+    )?.ok_or(CustomError::InvalidListing)?;
+
+    // payment accounts if the listing is priced in an SPL token
+    let mut payment_mint = None;
+    let mut alice_ata_payment = None;
+    let mut bob_payee = bob;
+    let mut neighborhood_creator_payee = fee_vault;
+    if listing_payment_mint != Pubkey::default() {
+        let payment_mint_info = next_account_info(account_info_iter)?;
+        let alice_ata_payment_info = next_account_info(account_info_iter)?;
+        let bob_ata_payment = next_account_info(account_info_iter)?;
+        let neighborhood_creator = next_account_info(account_info_iter)?;
+        let neighborhood_creator_ata_payment = next_account_info(account_info_iter)?;
+
+        // check payment mint matches listing
+        assert_keys_equal(listing_payment_mint, *payment_mint_info.key)?;
+
+        // check neighborhood creator is passed in correctly, as token fees are paid out directly
+        let neighborhood_metadata_data: NeighborhoodMetadata = try_from_slice_unchecked(&neighborhood_metadata.data.borrow())?;
+        assert_keys_equal(neighborhood_metadata_data.creator, *neighborhood_creator.key)?;
+
+        // create payment ATAs if necessary
+        create_ata_if_needed(alice, bob, payment_mint_info, bob_ata_payment, system_program, token_program, rent_program, associated_token_program)?;
+        create_ata_if_needed(alice, neighborhood_creator, payment_mint_info, neighborhood_creator_ata_payment, system_program, token_program, rent_program, associated_token_program)?;
+
+        // check ATAs
+        assert_is_ata(alice_ata_payment_info, alice.key, payment_mint_info.key)?;
+        assert_is_ata(bob_ata_payment, bob.key, payment_mint_info.key)?;
+        assert_is_ata(neighborhood_creator_ata_payment, neighborhood_creator.key, payment_mint_info.key)?;
+
+        payment_mint = Some(payment_mint_info);
+        alice_ata_payment = Some(alice_ata_payment_info);
+        bob_payee = bob_ata_payment;
+        neighborhood_creator_payee = neighborhood_creator_ata_payment;
+    }
+    else {
+        // create fee vault if necessary, token fees skip it
+        let (neighborhood_x, neighborhood_y) = get_neighborhood_xy(args.space_x, args.space_y);
+        create_fee_vault_if_needed(program_id, base, fee_vault, alice, system_program, neighborhood_x, neighborhood_y)?;
+    }
+
+    // transfer royalties to verified creators
+    let space_metaplex_metadata = next_account_info(account_info_iter)?;
+    let mut seller_proceeds = price
+        .checked_sub(marketplace_fee)
+        .ok_or(CustomError::NumericalOverflow)?;
+    for (creator, royalty) in get_royalties(space_metaplex_metadata, space_mint.key, price, seller_proceeds)? {
+        let creator_payee = next_account_info(account_info_iter)?;
+        if let Some(payment_mint) = payment_mint {
+            let creator_account = next_account_info(account_info_iter)?;
+            assert_keys_equal(creator, *creator_account.key)?;
+            create_ata_if_needed(alice, creator_account, payment_mint, creator_payee, system_program, token_program, rent_program, associated_token_program)?;
+            assert_is_ata(creator_payee, &creator, &listing_payment_mint)?;
+        }
+        else {
+            assert_keys_equal(creator, *creator_payee.key)?;
+        }
+        seller_proceeds = seller_proceeds
+            .checked_sub(royalty)
+            .ok_or(CustomError::NumericalOverflow)?;
+        transfer_payment(alice, alice_ata_payment, creator_payee, royalty, system_program, token_program)?;
+    }
+
+    // transfer payment from alice to bob
+    transfer_payment(alice, alice_ata_payment, bob_payee, seller_proceeds, system_program, token_program)?;
+
+    // transfer referrer share of marketplace fee
+    let mut creator_fee = marketplace_fee;
+    if let Some(referrer) = args.referrer {
+        let referrer_payee = next_account_info(account_info_iter)?;
+        if let Some(payment_mint) = payment_mint {
+            let referrer_account = next_account_info(account_info_iter)?;
+            assert_keys_equal(referrer, *referrer_account.key)?;
+            create_ata_if_needed(alice, referrer_account, payment_mint, referrer_payee, system_program, token_program, rent_program, associated_token_program)?;
+            assert_is_ata(referrer_payee, &referrer, &listing_payment_mint)?;
+        }
+        else {
+            assert_keys_equal(referrer, *referrer_payee.key)?;
+        }
+        let referral_fee = get_referral_fee(marketplace_fee, &base_data)?;
+        creator_fee -= referral_fee;
+        transfer_payment(alice, alice_ata_payment, referrer_payee, referral_fee, system_program, token_program)?;
+    }
+
+    // transfer marketplace fee to neighborhood fee vault, or to the neighborhood creator when priced in a token
+    transfer_payment(alice, alice_ata_payment, neighborhood_creator_payee, creator_fee, system_program, token_program)?;
+    if alice_ata_payment.is_none() {
+        record_fee(fee_vault, creator_fee)?;
+    }
+
+    msg!("done!");
+    Ok(())
+}
+
+// with skip_filled, a listing that is no longer available is passed over instead of failing
+fn unavailable(skip_filled: bool, error: ProgramError) -> Result<Option<(u64, u64, Pubkey)>, ProgramError> {
+    if skip_filled {
+        msg!("skipping listing");
+        return Ok(None);
+    }
+    Err(error)
+}
+
+// check bob's listing and move the space to alice, returning the price, marketplace fee and
+// payment mint alice owes, or None if skip_filled is set and the listing is no longer available
+// (or is priced in a token when sol_only is set)
+#[allow(clippy::too_many_arguments)]
+pub fn take_offer<'a>(
+    program_id: &Pubkey,
+    base: &AccountInfo<'a>,
+    base_data: &Base,
+    neighborhood_metadata: &AccountInfo<'a>,
+    space_metadata: &AccountInfo<'a>,
+    space_mint: &AccountInfo<'a>,
+    alice: &AccountInfo<'a>,
+    alice_ata_space: &AccountInfo<'a>,
+    bob: &AccountInfo<'a>,
+    bob_ata_space: &AccountInfo<'a>,
+    sell_delegate: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    associated_token_program: &AccountInfo<'a>,
+    rent_program: &AccountInfo<'a>,
+    listing_metadata: &AccountInfo<'a>,
+    args: &AcceptOfferArgs,
+    skip_filled: bool,
+    sol_only: bool,
+) -> Result<Option<(u64, u64, Pubkey)>, ProgramError> {
+    assert_not_paused(base_data)?;
+
     //deserialize and verify space metadata
     let mut space_metadata_data: SpaceMetadata = try_from_slice_unchecked(&space_metadata.data.borrow_mut())?;
     
@@ -88,61 +252,114 @@ pub fn process(
     let key = Pubkey::create_program_address(seeds_neighborhood_metadata, program_id)?;
     assert_keys_equal(key, *neighborhood_metadata.key)?;
 
-    // verify sell_delegate
-    let seeds_sell_delegate = &[&base.key.to_bytes(), SELL_DELEGATE_SEED];
-    let (key, bump_sell_delegate) = Pubkey::find_program_address(seeds_sell_delegate, program_id);
-    assert_keys_equal(key, *sell_delegate.key)?;
-    let seeds_sell_delegate = &[&base.key.to_bytes(), SELL_DELEGATE_SEED, &[bump_sell_delegate]];
+    // check listing is live
+    let now_ts = Clock::get()?.unix_timestamp;
+    if space_metadata_data.price == 0 {
+        msg!("Error: token not listed");
+        return unavailable(skip_filled, ProgramError::InvalidAccountData);
+    }
+    if space_metadata_data.expires_at != 0 && now_ts >= space_metadata_data.expires_at {
+        msg!("Error: listing expired");
+        return unavailable(skip_filled, CustomError::InvalidListing.into());
+    }
+    if sol_only && space_metadata_data.payment_mint != Pubkey::default() {
+        msg!("Error: listings priced in a token must be taken with AcceptOffer");
+        return unavailable(skip_filled, CustomError::InvalidListing.into());
+    }
 
-    // create ATA if necessary
-    if alice_ata_space.data_len() == 0 {
-        invoke(
-            &spl_associated_token_account::create_associated_token_account(
-                alice.key,
-                alice.key,
-                space_mint.key,
-            ),
-            &[
-                alice.clone(),
-                space_mint.clone(),
-                alice_ata_space.clone(),
-                system_program.clone(),
-                token_program.clone(),
-                rent_program.clone(),
-                associated_token_program.clone(),
-            ],
-        )?;
+    // deserialize and verify listing metadata
+    if listing_metadata.data_len() == 0 {
+        msg!("Error: listing predates seller binding, space must be relisted");
+        return unavailable(skip_filled, CustomError::InvalidListing.into());
     }
-    
-    // check ATAs
-    assert_is_ata(alice_ata_space, alice.key, space_mint.key)?;
-    assert_is_ata(bob_ata_space, bob.key, space_mint.key)?;
+    let mut listing_metadata_data: ListingMetadata = try_from_slice_unchecked(&listing_metadata.data.borrow())?;
+    let base_bytes = base.key.to_bytes();
+    let space_x_bytes = args.space_x.to_le_bytes();
+    let space_y_bytes = args.space_y.to_le_bytes();
+    let bump_listing_metadata = &[listing_metadata_data.bump];
+    let seeds_listing_metadata: &[&[u8]] = &[
+        &base_bytes,
+        LISTING_METADATA_SEED,
+        &space_x_bytes,
+        &space_y_bytes,
+        bump_listing_metadata,
+    ];
+    let key = Pubkey::create_program_address(seeds_listing_metadata, program_id)?;
+    assert_keys_equal(key, *listing_metadata.key)?;
 
-    // check neighborhood creator is passed in correctly
-    assert_keys_equal(neighborhood_metadata_data.creator, *neighborhood_creator.key)?;
+    // check bob listed the space from the token account it is sold from
+    if listing_metadata_data.seller != *bob.key || listing_metadata_data.token_account != *bob_ata_space.key {
+        msg!("Error: listing was made by a previous owner");
+        return unavailable(skip_filled, CustomError::InvalidListing.into());
+    }
 
-    // check that B is listed
-    let bob_ata_space_data = spl_token::state::Account::unpack(&bob_ata_space.data.borrow())?;
-    if !bob_ata_space_data.delegate.contains(sell_delegate.key) {
-        msg!("Error: token not listed");
-        return Err(ProgramError::InvalidAccountData);
+    // check alice may take a private listing
+    if listing_metadata_data.buyer != Pubkey::default() && listing_metadata_data.buyer != *alice.key {
+        msg!("Error: listing is reserved for another buyer");
+        return Err(CustomError::InvalidListing.into());
+    }
+
+    // vault listings are released by the listing metadata, others by the sell delegate
+    let bump_sell_delegate;
+    let (space_authority, seeds_space_authority): (&AccountInfo, &[&[u8]]) = if listing_metadata_data.vault {
+        // verify listing vault
+        let seeds_listing_vault: &[&[u8]] = &[
+            &base_bytes,
+            LISTING_VAULT_SEED,
+            &space_x_bytes,
+            &space_y_bytes,
+            &[listing_metadata_data.vault_bump],
+        ];
+        let key = Pubkey::create_program_address(seeds_listing_vault, program_id)?;
+        assert_keys_equal(key, *bob_ata_space.key)?;
+
+        (listing_metadata, seeds_listing_metadata)
     }
+    else {
+        assert_is_ata(bob_ata_space, bob.key, space_mint.key)?;
 
-    // check user price equals true price
-    if space_metadata_data.price != args.price {
-        msg!("space metadata price is {}",space_metadata_data.price);
-        msg!("args price is {}",args.price);
+        // check that B is listed
+        let bob_ata_space_data = spl_token::state::Account::unpack(&bob_ata_space.data.borrow())?;
+        if !bob_ata_space_data.delegate.contains(sell_delegate.key) || bob_ata_space_data.amount != 1 {
+            msg!("Error: token not listed");
+            return unavailable(skip_filled, ProgramError::InvalidAccountData);
+        }
+
+        // verify sell_delegate
+        let (key, bump) = Pubkey::find_program_address(
+            &[&base_bytes, SELL_DELEGATE_SEED, &space_x_bytes, &space_y_bytes],
+            program_id,
+        );
+        bump_sell_delegate = [bump];
+        if key != *sell_delegate.key {
+            msg!("Error: sell delegate does not match space");
+            return Err(CustomError::PublicKeyMismatch.into());
+        }
+        (sell_delegate, &[&base_bytes, SELL_DELEGATE_SEED, &space_x_bytes, &space_y_bytes, &bump_sell_delegate])
+    };
+
+    // check current price is within what the user accepts
+    let price = get_listing_price(&space_metadata_data, now_ts);
+    if price > args.max_price {
+        msg!("listing price is {}",price);
+        msg!("args max price is {}",args.max_price);
         msg!("Error: listing has changed");
-        return Err(ProgramError::InvalidInstructionData);
+        return unavailable(skip_filled, ProgramError::InvalidInstructionData);
     }
 
+    // create ATA if necessary
+    create_ata_if_needed(alice, alice, space_mint, alice_ata_space, system_program, token_program, rent_program, associated_token_program)?;
+    
+    // check ATAs
+    assert_is_ata(alice_ata_space, alice.key, space_mint.key)?;
+
     // transfer NFT from bob to alice
     invoke_signed(
         &spl_token::instruction::transfer(
             token_program.key,
             bob_ata_space.key,
             alice_ata_space.key,
-            sell_delegate.key,
+            space_authority.key,
             &[],
             1,
         )?,
@@ -150,44 +367,38 @@ pub fn process(
             token_program.clone(),
             bob_ata_space.clone(),
             alice_ata_space.clone(),
-            sell_delegate.clone(),
+            space_authority.clone(),
         ],
-        &[seeds_sell_delegate],
+        &[seeds_space_authority],
     )?;
 
-    let marketplace_fee: u64 = (args.price as f64 * MARKETPLACE_FEE) as u64;
-    
-    // transfer SOL from alice to bob
-    invoke(
-        &system_instruction::transfer(
-            alice.key,
-            bob.key,
-            args.price - marketplace_fee,
-        ),
-        &[
-            alice.clone(),
-            bob.clone(),
-            system_program.clone(),
-        ],
-    )?;
+    // close listing vault, returning rent to bob
+    if listing_metadata_data.vault {
+        invoke_signed(
+            &spl_token::instruction::close_account(
+                token_program.key,
+                bob_ata_space.key,
+                bob.key,
+                listing_metadata.key,
+                &[],
+            )?,
+            &[
+                token_program.clone(),
+                bob_ata_space.clone(),
+                bob.clone(),
+                listing_metadata.clone(),
+            ],
+            &[seeds_listing_metadata],
+        )?;
+    }
 
-    // transfer marketplace fee to neighborhood creator
-    invoke(
-        &system_instruction::transfer(
-            alice.key,
-            neighborhood_creator.key,
-            marketplace_fee,
-        ),
-        &[
-            alice.clone(),
-            neighborhood_creator.clone(),
-            system_program.clone(),
-        ],
-    )?;
+    let marketplace_fee = get_marketplace_fee(price, get_marketplace_fee_bps(base_data, &neighborhood_metadata_data))?;
+    let payment_mint = space_metadata_data.payment_mint;
 
-    space_metadata_data.price = 0;
+    clear_listing(&mut space_metadata_data);
     space_metadata_data.serialize(&mut *space_metadata.data.borrow_mut())?;
+    clear_listing_metadata(&mut listing_metadata_data);
+    listing_metadata_data.serialize(&mut *listing_metadata.data.borrow_mut())?;
 
-    msg!("done!");
-    Ok(())
+    Ok(Some((price, marketplace_fee, payment_mint)))
 }
@@ -0,0 +1,161 @@
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    borsh::try_from_slice_unchecked,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+    sysvar::rent,
+};
+use spl_associated_token_account;
+use spl_token;
+
+use crate::{
+    error::CustomError,
+    instruction::AcceptOffersArgs,
+    processor::{
+        accept_offer::take_offer,
+        processor_utils::{assert_valid_referrer, create_fee_vault_if_needed, get_neighborhood_xy, get_referral_fee, get_royalties, record_fee, transfer_payment},
+    },
+    state::Base,
+    validation_utils::{assert_keys_equal, assert_owned_by},
+};
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &AcceptOffersArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let base = next_account_info(account_info_iter)?;
+    let alice = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
+    let token_program = next_account_info(account_info_iter)?;
+    let associated_token_program = next_account_info(account_info_iter)?;
+    let rent_program = next_account_info(account_info_iter)?;
+
+    if !alice.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // check programs
+    assert_keys_equal(system_program::id(), *system_program.key)?;
+    assert_keys_equal(spl_token::id(), *token_program.key)?;
+    assert_keys_equal(spl_associated_token_account::id(), *associated_token_program.key)?;
+    assert_keys_equal(rent::id(), *rent_program.key)?;
+
+    // deserialize base
+    assert_owned_by(base, program_id)?;
+    let base_data: Base = try_from_slice_unchecked(&base.data.borrow())?;
+
+    if args.offers.is_empty() {
+        msg!("Error: no offers to accept");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let mut total: u64 = 0;
+    let mut fees: Vec<(&AccountInfo, u64)> = Vec::new();
+    for offer in args.offers.iter() {
+        let neighborhood_metadata = next_account_info(account_info_iter)?;
+        let fee_vault = next_account_info(account_info_iter)?;
+        let space_metadata = next_account_info(account_info_iter)?;
+        let space_mint = next_account_info(account_info_iter)?;
+        let alice_ata_space = next_account_info(account_info_iter)?;
+        let bob = next_account_info(account_info_iter)?;
+        let bob_ata_space = next_account_info(account_info_iter)?;
+        let sell_delegate = next_account_info(account_info_iter)?;
+        let listing_metadata = next_account_info(account_info_iter)?;
+        let space_metaplex_metadata = next_account_info(account_info_iter)?;
+
+        assert_valid_referrer(&offer.referrer, alice.key, bob.key)?;
+
+        let (price, marketplace_fee, _) = match take_offer(
+            program_id,
+            base,
+            &base_data,
+            neighborhood_metadata,
+            space_metadata,
+            space_mint,
+            alice,
+            alice_ata_space,
+            bob,
+            bob_ata_space,
+            sell_delegate,
+            system_program,
+            token_program,
+            associated_token_program,
+            rent_program,
+            listing_metadata,
+            offer,
+            args.skip_filled,
+            true,
+        )? {
+            Some(taken) => taken,
+            None => {
+                // pass over the creator accounts of the skipped listing
+                for _ in get_royalties(space_metaplex_metadata, space_mint.key, 0, 0)? {
+                    next_account_info(account_info_iter)?;
+                }
+                if offer.referrer.is_some() {
+                    next_account_info(account_info_iter)?;
+                }
+                continue;
+            }
+        };
+
+        // create fee vault if necessary
+        let (neighborhood_x, neighborhood_y) = get_neighborhood_xy(offer.space_x, offer.space_y);
+        create_fee_vault_if_needed(program_id, base, fee_vault, alice, system_program, neighborhood_x, neighborhood_y)?;
+
+        // check running total is within what the user accepts
+        total = total.checked_add(price).ok_or(CustomError::NumericalOverflow)?;
+        if total > args.max_total {
+            msg!("total price is at least {}", total);
+            msg!("args max total is {}", args.max_total);
+            msg!("Error: listings have changed");
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        // transfer royalties to verified creators
+        let mut seller_proceeds = price
+            .checked_sub(marketplace_fee)
+            .ok_or(CustomError::NumericalOverflow)?;
+        for (creator, royalty) in get_royalties(space_metaplex_metadata, space_mint.key, price, seller_proceeds)? {
+            let creator_account = next_account_info(account_info_iter)?;
+            assert_keys_equal(creator, *creator_account.key)?;
+            seller_proceeds = seller_proceeds
+                .checked_sub(royalty)
+                .ok_or(CustomError::NumericalOverflow)?;
+            transfer_payment(alice, None, creator_account, royalty, system_program, token_program)?;
+        }
+
+        // transfer SOL from alice to bob
+        transfer_payment(alice, None, bob, seller_proceeds, system_program, token_program)?;
+
+        // transfer referrer share of marketplace fee
+        let mut creator_fee = marketplace_fee;
+        if let Some(referrer) = offer.referrer {
+            let referrer_account = next_account_info(account_info_iter)?;
+            assert_keys_equal(referrer, *referrer_account.key)?;
+            let referral_fee = get_referral_fee(marketplace_fee, &base_data)?;
+            creator_fee -= referral_fee;
+            transfer_payment(alice, None, referrer_account, referral_fee, system_program, token_program)?;
+        }
+
+        // collect marketplace fee per neighborhood fee vault
+        match fees.iter_mut().find(|(vault, _)| vault.key == fee_vault.key) {
+            Some((_, fee)) => *fee = fee.checked_add(creator_fee).ok_or(CustomError::NumericalOverflow)?,
+            None => fees.push((fee_vault, creator_fee)),
+        }
+    }
+
+    // transfer marketplace fees to neighborhood fee vaults
+    for (fee_vault, fee) in fees {
+        transfer_payment(alice, None, fee_vault, fee, system_program, token_program)?;
+        record_fee(fee_vault, fee)?;
+    }
+
+    msg!("done!");
+    Ok(())
+}
@@ -0,0 +1,268 @@
+use borsh::{BorshSerialize};
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    borsh::try_from_slice_unchecked,
+    entrypoint::ProgramResult,
+    msg,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    system_instruction, system_program,
+    sysvar::{rent},
+};
+use spl_associated_token_account;
+use spl_token;
+
+use crate::{
+    error::CustomError,
+    instruction::AcceptSwapArgs,
+    processor::processor_utils::{assert_not_paused, clear_listing, clear_listing_metadata, close_account, create_ata_if_needed, transfer_lamports},
+    state::{
+        LISTING_METADATA_SEED,
+        SPACE_METADATA_SEED,
+        SWAP_SEED,
+        SWAP_VAULT_SEED,
+        Base,
+        ListingMetadata,
+        SpaceMetadata,
+        Swap,
+    },
+    validation_utils::{assert_is_ata, assert_keys_equal, assert_owned_by},
+};
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &AcceptSwapArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let base = next_account_info(account_info_iter)?;
+    let offered_space_metadata = next_account_info(account_info_iter)?;
+    let wanted_space_metadata = next_account_info(account_info_iter)?;
+    let offered_mint = next_account_info(account_info_iter)?;
+    let wanted_mint = next_account_info(account_info_iter)?;
+    let swap = next_account_info(account_info_iter)?;
+    let swap_vault = next_account_info(account_info_iter)?;
+    let proposer = next_account_info(account_info_iter)?;
+    let proposer_ata_wanted = next_account_info(account_info_iter)?;
+    let acceptor = next_account_info(account_info_iter)?;
+    let acceptor_ata_offered = next_account_info(account_info_iter)?;
+    let acceptor_ata_wanted = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
+    let token_program = next_account_info(account_info_iter)?;
+    let associated_token_program = next_account_info(account_info_iter)?;
+    let rent_program = next_account_info(account_info_iter)?;
+    let offered_listing_metadata = next_account_info(account_info_iter)?;
+    let wanted_listing_metadata = next_account_info(account_info_iter)?;
+
+    if !acceptor.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // check world is not paused
+    assert_owned_by(base, program_id)?;
+    let base_data: Base = try_from_slice_unchecked(&base.data.borrow())?;
+    assert_not_paused(&base_data)?;
+
+    // check programs
+    assert_keys_equal(system_program::id(), *system_program.key)?;
+    assert_keys_equal(spl_token::id(), *token_program.key)?;
+    assert_keys_equal(spl_associated_token_account::id(), *associated_token_program.key)?;
+    assert_keys_equal(rent::id(), *rent_program.key)?;
+
+    // deserialize and verify swap account
+    let swap_data: Swap = try_from_slice_unchecked(&swap.data.borrow())?;
+    let seeds_swap = &[
+        &base.key.to_bytes(),
+        SWAP_SEED,
+        &args.offered_x.to_le_bytes(),
+        &args.offered_y.to_le_bytes(),
+        &args.wanted_x.to_le_bytes(),
+        &args.wanted_y.to_le_bytes(),
+        &[swap_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_swap, program_id)?;
+    assert_keys_equal(key, *swap.key)?;
+    assert_keys_equal(swap_data.proposer, *proposer.key)?;
+
+    // verify swap vault
+    let seeds_swap_vault = &[
+        &base.key.to_bytes(),
+        SWAP_VAULT_SEED,
+        &args.offered_x.to_le_bytes(),
+        &args.offered_y.to_le_bytes(),
+        &args.wanted_x.to_le_bytes(),
+        &args.wanted_y.to_le_bytes(),
+        &[swap_data.vault_bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_swap_vault, program_id)?;
+    assert_keys_equal(key, *swap_vault.key)?;
+
+    // check top ups equal what the acceptor agreed to
+    if swap_data.proposer_top_up != args.proposer_top_up || swap_data.acceptor_top_up != args.acceptor_top_up {
+        msg!("swap top ups are {} and {}", swap_data.proposer_top_up, swap_data.acceptor_top_up);
+        msg!("Error: swap has changed");
+        return Err(CustomError::NotSwappable.into());
+    }
+
+    // deserialize and verify both space metadata accounts against the swap
+    let mut offered_space_metadata_data: SpaceMetadata = try_from_slice_unchecked(&offered_space_metadata.data.borrow())?;
+    let seeds_offered_space_metadata = &[
+        &base.key.to_bytes(),
+        SPACE_METADATA_SEED,
+        &args.offered_x.to_le_bytes(),
+        &args.offered_y.to_le_bytes(),
+        &[offered_space_metadata_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_offered_space_metadata, program_id)?;
+    assert_keys_equal(key, *offered_space_metadata.key)?;
+
+    let mut wanted_space_metadata_data: SpaceMetadata = try_from_slice_unchecked(&wanted_space_metadata.data.borrow())?;
+    let seeds_wanted_space_metadata = &[
+        &base.key.to_bytes(),
+        SPACE_METADATA_SEED,
+        &args.wanted_x.to_le_bytes(),
+        &args.wanted_y.to_le_bytes(),
+        &[wanted_space_metadata_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_wanted_space_metadata, program_id)?;
+    assert_keys_equal(key, *wanted_space_metadata.key)?;
+
+    // verify both listing metadata accounts
+    let seeds_offered_listing_metadata = &[
+        &base.key.to_bytes(),
+        LISTING_METADATA_SEED,
+        &args.offered_x.to_le_bytes(),
+        &args.offered_y.to_le_bytes(),
+    ];
+    let (key, _) = Pubkey::find_program_address(seeds_offered_listing_metadata, program_id);
+    assert_keys_equal(key, *offered_listing_metadata.key)?;
+
+    let seeds_wanted_listing_metadata = &[
+        &base.key.to_bytes(),
+        LISTING_METADATA_SEED,
+        &args.wanted_x.to_le_bytes(),
+        &args.wanted_y.to_le_bytes(),
+    ];
+    let (key, _) = Pubkey::find_program_address(seeds_wanted_listing_metadata, program_id);
+    assert_keys_equal(key, *wanted_listing_metadata.key)?;
+
+    if offered_space_metadata_data.mint != *offered_mint.key
+        || wanted_space_metadata_data.mint != *wanted_mint.key
+        || swap_data.offered_mint != *offered_mint.key
+        || swap_data.wanted_mint != *wanted_mint.key
+    {
+        msg!("Error: swap does not match mint accounts");
+        return Err(CustomError::MintMismatch.into());
+    }
+
+    // create ATAs if necessary
+    create_ata_if_needed(acceptor, acceptor, offered_mint, acceptor_ata_offered, system_program, token_program, rent_program, associated_token_program)?;
+    create_ata_if_needed(acceptor, proposer, wanted_mint, proposer_ata_wanted, system_program, token_program, rent_program, associated_token_program)?;
+
+    // check ATAs
+    assert_is_ata(acceptor_ata_offered, acceptor.key, offered_mint.key)?;
+    assert_is_ata(acceptor_ata_wanted, acceptor.key, wanted_mint.key)?;
+    assert_is_ata(proposer_ata_wanted, proposer.key, wanted_mint.key)?;
+
+    // check NFT owned
+    let acceptor_ata_wanted_data = spl_token::state::Account::unpack(&acceptor_ata_wanted.data.borrow())?;
+    if acceptor_ata_wanted_data.amount != 1 {
+        msg!("Error: token account does not own token");
+        return Err(CustomError::MissingTokenOwner.into());
+    }
+
+    // transfer B from acceptor to proposer
+    invoke(
+        &spl_token::instruction::transfer(
+            token_program.key,
+            acceptor_ata_wanted.key,
+            proposer_ata_wanted.key,
+            acceptor.key,
+            &[],
+            1,
+        )?,
+        &[
+            token_program.clone(),
+            acceptor_ata_wanted.clone(),
+            proposer_ata_wanted.clone(),
+            acceptor.clone(),
+        ],
+    )?;
+
+    // transfer A from swap vault to acceptor
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program.key,
+            swap_vault.key,
+            acceptor_ata_offered.key,
+            swap.key,
+            &[],
+            1,
+        )?,
+        &[
+            token_program.clone(),
+            swap_vault.clone(),
+            acceptor_ata_offered.clone(),
+            swap.clone(),
+        ],
+        &[seeds_swap],
+    )?;
+
+    // close swap vault, returning rent to proposer
+    invoke_signed(
+        &spl_token::instruction::close_account(
+            token_program.key,
+            swap_vault.key,
+            proposer.key,
+            swap.key,
+            &[],
+        )?,
+        &[
+            token_program.clone(),
+            swap_vault.clone(),
+            proposer.clone(),
+            swap.clone(),
+        ],
+        &[seeds_swap],
+    )?;
+
+    // settle top ups
+    if swap_data.proposer_top_up > 0 {
+        transfer_lamports(swap, acceptor, swap_data.proposer_top_up)?;
+    }
+    if swap_data.acceptor_top_up > 0 {
+        invoke(
+            &system_instruction::transfer(
+                acceptor.key,
+                proposer.key,
+                swap_data.acceptor_top_up,
+            ),
+            &[
+                acceptor.clone(),
+                proposer.clone(),
+                system_program.clone(),
+            ],
+        )?;
+    }
+
+    // close swap account, returning rent to proposer
+    close_account(swap, proposer)?;
+
+    // both spaces changed hands, so any sell offers are void
+    clear_listing(&mut offered_space_metadata_data);
+    offered_space_metadata_data.serialize(&mut *offered_space_metadata.data.borrow_mut())?;
+    clear_listing(&mut wanted_space_metadata_data);
+    wanted_space_metadata_data.serialize(&mut *wanted_space_metadata.data.borrow_mut())?;
+    for listing_metadata in [offered_listing_metadata, wanted_listing_metadata] {
+        if listing_metadata.data_len() != 0 {
+            let mut listing_metadata_data: ListingMetadata = try_from_slice_unchecked(&listing_metadata.data.borrow())?;
+            clear_listing_metadata(&mut listing_metadata_data);
+            listing_metadata_data.serialize(&mut *listing_metadata.data.borrow_mut())?;
+        }
+    }
+
+    msg!("done!");
+    Ok(())
+}
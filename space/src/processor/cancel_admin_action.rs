@@ -0,0 +1,58 @@
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    borsh::try_from_slice_unchecked,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    instruction::CancelAdminActionArgs,
+    processor::processor_utils::close_account,
+    state::{
+        ADMIN_ACTION_SEED,
+        Base,
+        QueuedAdminAction,
+    },
+    validation_utils::{assert_keys_equal, assert_owned_by},
+};
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &CancelAdminActionArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let base = next_account_info(account_info_iter)?;
+    let authority = next_account_info(account_info_iter)?;
+    let queued_action = next_account_info(account_info_iter)?;
+
+    assert_owned_by(base, program_id)?;
+    let base_data: Base = try_from_slice_unchecked(&base.data.borrow())?;
+
+    if !authority.is_signer {
+        msg!("Error: Missing signer");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // check that signer is authority of base
+    assert_keys_equal(base_data.authority, *authority.key)?;
+
+    // deserialize and verify queued action
+    assert_owned_by(queued_action, program_id)?;
+    let queued_action_data: QueuedAdminAction = try_from_slice_unchecked(&queued_action.data.borrow())?;
+    let seeds_queued_action = &[
+        &base.key.to_bytes(),
+        ADMIN_ACTION_SEED,
+        &args.id.to_le_bytes(),
+        &[queued_action_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_queued_action, program_id)?;
+    assert_keys_equal(key, *queued_action.key)?;
+
+    // close queued action, returning rent to authority
+    close_account(queued_action, authority)?;
+
+    Ok(())
+}
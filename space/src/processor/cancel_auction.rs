@@ -0,0 +1,117 @@
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    borsh::try_from_slice_unchecked,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use spl_token;
+
+use crate::{
+    instruction::CancelAuctionArgs,
+    processor::processor_utils::close_account,
+    state::{
+        AUCTION_SEED,
+        AUCTION_VAULT_SEED,
+        Auction,
+    },
+    validation_utils::{assert_is_ata, assert_keys_equal},
+};
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &CancelAuctionArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let base = next_account_info(account_info_iter)?;
+    let auction = next_account_info(account_info_iter)?;
+    let auction_vault = next_account_info(account_info_iter)?;
+    let seller = next_account_info(account_info_iter)?;
+    let seller_ata_space = next_account_info(account_info_iter)?;
+    let token_program = next_account_info(account_info_iter)?;
+
+    if !seller.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // check programs
+    assert_keys_equal(spl_token::id(), *token_program.key)?;
+
+    // deserialize and verify auction account
+    let auction_data: Auction = try_from_slice_unchecked(&auction.data.borrow())?;
+    let seeds_auction = &[
+        &base.key.to_bytes(),
+        AUCTION_SEED,
+        &args.space_x.to_le_bytes(),
+        &args.space_y.to_le_bytes(),
+        &[auction_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_auction, program_id)?;
+    assert_keys_equal(key, *auction.key)?;
+    assert_keys_equal(auction_data.seller, *seller.key)?;
+
+    // verify auction vault
+    let seeds_auction_vault = &[
+        &base.key.to_bytes(),
+        AUCTION_VAULT_SEED,
+        &args.space_x.to_le_bytes(),
+        &args.space_y.to_le_bytes(),
+        &[auction_data.vault_bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_auction_vault, program_id)?;
+    assert_keys_equal(key, *auction_vault.key)?;
+
+    // bidders are owed a settlement once anyone has bid
+    if auction_data.highest_bid > 0 {
+        msg!("Error: auction already has bids");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // check ATAs
+    assert_is_ata(seller_ata_space, seller.key, &auction_data.mint)?;
+
+    // transfer NFT from auction vault back to seller
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program.key,
+            auction_vault.key,
+            seller_ata_space.key,
+            auction.key,
+            &[],
+            1,
+        )?,
+        &[
+            token_program.clone(),
+            auction_vault.clone(),
+            seller_ata_space.clone(),
+            auction.clone(),
+        ],
+        &[seeds_auction],
+    )?;
+
+    // close auction vault, returning rent to seller
+    invoke_signed(
+        &spl_token::instruction::close_account(
+            token_program.key,
+            auction_vault.key,
+            seller.key,
+            auction.key,
+            &[],
+        )?,
+        &[
+            token_program.clone(),
+            auction_vault.clone(),
+            seller.clone(),
+            auction.clone(),
+        ],
+        &[seeds_auction],
+    )?;
+
+    // close auction account, returning rent to seller
+    close_account(auction, seller)?;
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use borsh::{BorshSerialize};
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    borsh::try_from_slice_unchecked,
+    entrypoint::ProgramResult,
+    pubkey::Pubkey,
+    program_error::ProgramError,
+    msg,
+};
+
+use crate::{
+    instruction::CancelAuthorityArgs,
+    state::Base,
+    validation_utils::{assert_keys_equal, assert_owned_by},
+};
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _args: &CancelAuthorityArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let base = next_account_info(account_info_iter)?;
+    let current_creator = next_account_info(account_info_iter)?;
+
+    assert_owned_by(base, program_id)?;
+    let mut base_data: Base = try_from_slice_unchecked(&base.data.borrow_mut())?;
+
+    if !current_creator.is_signer {
+        msg!("Error: Missing signer");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // check that canceller is creator of base
+    assert_keys_equal(base_data.authority, *current_creator.key)?;
+
+    // write to base
+    base_data.pending_authority = None;
+    base_data.serialize(&mut *base.data.borrow_mut())?;
+
+    Ok(())
+}
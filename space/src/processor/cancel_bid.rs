@@ -0,0 +1,51 @@
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    borsh::try_from_slice_unchecked,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    instruction::CancelBidArgs,
+    processor::processor_utils::close_account,
+    state::{
+        BID_SEED,
+        Bid,
+    },
+    validation_utils::assert_keys_equal,
+};
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &CancelBidArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let base = next_account_info(account_info_iter)?;
+    let bid = next_account_info(account_info_iter)?;
+    let bidder = next_account_info(account_info_iter)?;
+
+    if !bidder.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // deserialize and verify bid account
+    let bid_data: Bid = try_from_slice_unchecked(&bid.data.borrow())?;
+    let seeds_bid = &[
+        &base.key.to_bytes(),
+        BID_SEED,
+        &args.space_x.to_le_bytes(),
+        &args.space_y.to_le_bytes(),
+        &bidder.key.to_bytes(),
+        &[bid_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_bid, program_id)?;
+    assert_keys_equal(key, *bid.key)?;
+    assert_keys_equal(bid_data.bidder, *bidder.key)?;
+
+    // refund escrow and rent to bidder
+    close_account(bid, bidder)?;
+
+    Ok(())
+}
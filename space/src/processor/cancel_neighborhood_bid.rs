@@ -0,0 +1,51 @@
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    borsh::try_from_slice_unchecked,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    instruction::CancelNeighborhoodBidArgs,
+    processor::processor_utils::close_account,
+    state::{
+        NEIGHBORHOOD_BID_SEED,
+        NeighborhoodBid,
+    },
+    validation_utils::assert_keys_equal,
+};
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &CancelNeighborhoodBidArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let base = next_account_info(account_info_iter)?;
+    let neighborhood_bid = next_account_info(account_info_iter)?;
+    let bidder = next_account_info(account_info_iter)?;
+
+    if !bidder.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // deserialize and verify neighborhood bid account
+    let neighborhood_bid_data: NeighborhoodBid = try_from_slice_unchecked(&neighborhood_bid.data.borrow())?;
+    let seeds_neighborhood_bid = &[
+        &base.key.to_bytes(),
+        NEIGHBORHOOD_BID_SEED,
+        &args.neighborhood_x.to_le_bytes(),
+        &args.neighborhood_y.to_le_bytes(),
+        &bidder.key.to_bytes(),
+        &[neighborhood_bid_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_neighborhood_bid, program_id)?;
+    assert_keys_equal(key, *neighborhood_bid.key)?;
+    assert_keys_equal(neighborhood_bid_data.bidder, *bidder.key)?;
+
+    // refund remaining escrow and rent to bidder
+    close_account(neighborhood_bid, bidder)?;
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    borsh::try_from_slice_unchecked,
+    entrypoint::ProgramResult,
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use spl_token;
+
+use crate::{
+    instruction::CancelSwapArgs,
+    processor::processor_utils::close_account,
+    state::{
+        SWAP_SEED,
+        SWAP_VAULT_SEED,
+        Swap,
+    },
+    validation_utils::{assert_is_ata, assert_keys_equal},
+};
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &CancelSwapArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let base = next_account_info(account_info_iter)?;
+    let swap = next_account_info(account_info_iter)?;
+    let swap_vault = next_account_info(account_info_iter)?;
+    let proposer = next_account_info(account_info_iter)?;
+    let proposer_ata_offered = next_account_info(account_info_iter)?;
+    let token_program = next_account_info(account_info_iter)?;
+
+    if !proposer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // check programs
+    assert_keys_equal(spl_token::id(), *token_program.key)?;
+
+    // deserialize and verify swap account
+    let swap_data: Swap = try_from_slice_unchecked(&swap.data.borrow())?;
+    let seeds_swap = &[
+        &base.key.to_bytes(),
+        SWAP_SEED,
+        &args.offered_x.to_le_bytes(),
+        &args.offered_y.to_le_bytes(),
+        &args.wanted_x.to_le_bytes(),
+        &args.wanted_y.to_le_bytes(),
+        &[swap_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_swap, program_id)?;
+    assert_keys_equal(key, *swap.key)?;
+    assert_keys_equal(swap_data.proposer, *proposer.key)?;
+
+    // verify swap vault
+    let seeds_swap_vault = &[
+        &base.key.to_bytes(),
+        SWAP_VAULT_SEED,
+        &args.offered_x.to_le_bytes(),
+        &args.offered_y.to_le_bytes(),
+        &args.wanted_x.to_le_bytes(),
+        &args.wanted_y.to_le_bytes(),
+        &[swap_data.vault_bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_swap_vault, program_id)?;
+    assert_keys_equal(key, *swap_vault.key)?;
+
+    // check ATAs
+    assert_is_ata(proposer_ata_offered, proposer.key, &swap_data.offered_mint)?;
+
+    // transfer NFT from swap vault back to proposer
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program.key,
+            swap_vault.key,
+            proposer_ata_offered.key,
+            swap.key,
+            &[],
+            1,
+        )?,
+        &[
+            token_program.clone(),
+            swap_vault.clone(),
+            proposer_ata_offered.clone(),
+            swap.clone(),
+        ],
+        &[seeds_swap],
+    )?;
+
+    // close swap vault, returning rent to proposer
+    invoke_signed(
+        &spl_token::instruction::close_account(
+            token_program.key,
+            swap_vault.key,
+            proposer.key,
+            swap.key,
+            &[],
+        )?,
+        &[
+            token_program.clone(),
+            swap_vault.clone(),
+            proposer.clone(),
+            swap.clone(),
+        ],
+        &[seeds_swap],
+    )?;
+
+    // refund escrowed top up and rent to proposer
+    close_account(swap, proposer)?;
+
+    Ok(())
+}
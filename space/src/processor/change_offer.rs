@@ -3,11 +3,14 @@ use solana_program::{
     account_info::{AccountInfo, next_account_info},
     entrypoint::ProgramResult,
     borsh::try_from_slice_unchecked,
+    clock::Clock,
     msg,
-    program::invoke,
+    program::{invoke, invoke_signed},
     program_error::ProgramError,
     program_pack::Pack,
     pubkey::Pubkey,
+    system_program,
+    sysvar::{Sysvar, rent},
 };
 use spl_token;
 
@@ -15,11 +18,17 @@ use crate::{
     error::CustomError,
     instruction::ChangeOfferArgs,
     state::{
+        LISTING_METADATA_SEED,
+        LISTING_METADATA_RESERVE,
+        LISTING_VAULT_SEED,
         SPACE_METADATA_SEED,
         SELL_DELEGATE_SEED,
+        Base,
+        ListingMetadata,
         SpaceMetadata,
     },
-    validation_utils::{assert_is_ata, assert_keys_equal},
+    processor::processor_utils::{assert_not_paused, clear_listing, clear_listing_metadata, create_pda_account},
+    validation_utils::{assert_is_ata, assert_keys_equal, assert_owned_by},
 };
 
 pub fn process(
@@ -34,13 +43,53 @@ pub fn process(
     let ata_account = next_account_info(account_info_iter)?;
     let sell_delegate = next_account_info(account_info_iter)?;
     let token_program = next_account_info(account_info_iter)?;
+    let listing_metadata = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
 
+    change_offer(
+        program_id,
+        base,
+        owner,
+        token_program,
+        system_program,
+        space_metadata,
+        ata_account,
+        sell_delegate,
+        listing_metadata,
+        account_info_iter,
+        args,
+    )
+}
+
+// list or delist one space, taking the listing vault accounts from account_info_iter when needed
+#[allow(clippy::too_many_arguments)]
+pub fn change_offer<'a, 'b: 'a, I: Iterator<Item = &'a AccountInfo<'b>>>(
+    program_id: &Pubkey,
+    base: &'a AccountInfo<'b>,
+    owner: &'a AccountInfo<'b>,
+    token_program: &'a AccountInfo<'b>,
+    system_program: &'a AccountInfo<'b>,
+    space_metadata: &'a AccountInfo<'b>,
+    ata_account: &'a AccountInfo<'b>,
+    sell_delegate: &'a AccountInfo<'b>,
+    listing_metadata: &'a AccountInfo<'b>,
+    account_info_iter: &mut I,
+    args: &ChangeOfferArgs,
+) -> ProgramResult {
     if !owner.is_signer {
         return Err(ProgramError::MissingRequiredSignature);
     }
     
     // check programs
     assert_keys_equal(spl_token::id(), *token_program.key)?;
+    assert_keys_equal(system_program::id(), *system_program.key)?;
+
+    // delisting keeps working while paused
+    if args.create {
+        assert_owned_by(base, program_id)?;
+        let base_data: Base = try_from_slice_unchecked(&base.data.borrow())?;
+        assert_not_paused(&base_data)?;
+    }
 
     //deserialize and verify space metadata
     let mut space_metadata_data: SpaceMetadata = try_from_slice_unchecked(&space_metadata.data.borrow_mut())?;
@@ -55,23 +104,212 @@ pub fn process(
     let key = Pubkey::create_program_address(seeds_space_metadata, program_id)?;
     assert_keys_equal(key, *space_metadata.key)?;
 
+    // verify listing metadata
+    let seeds_listing_metadata = &[
+        &base.key.to_bytes(),
+        LISTING_METADATA_SEED,
+        &args.space_x.to_le_bytes(),
+        &args.space_y.to_le_bytes(),
+    ];
+    let (key, bump_listing_metadata) = Pubkey::find_program_address(seeds_listing_metadata, program_id);
+    assert_keys_equal(key, *listing_metadata.key)?;
+    let seeds_listing_metadata = &[
+        &base.key.to_bytes(),
+        LISTING_METADATA_SEED,
+        &args.space_x.to_le_bytes(),
+        &args.space_y.to_le_bytes(),
+        &[bump_listing_metadata],
+    ];
+
     //verify sell delegate
-    let seeds_sell_delegate = &[&base.key.to_bytes(), SELL_DELEGATE_SEED];
+    let seeds_sell_delegate = &[
+        &base.key.to_bytes(),
+        SELL_DELEGATE_SEED,
+        &args.space_x.to_le_bytes(),
+        &args.space_y.to_le_bytes(),
+    ];
     let (key, _) = Pubkey::find_program_address(seeds_sell_delegate, program_id);
     assert_keys_equal(key, *sell_delegate.key)?;
 
     //check ATAs
     assert_is_ata(ata_account, owner.key, &space_metadata_data.mint)?;
 
+    // a space already in the listing vault can only be changed by whoever listed it
+    let in_vault = if listing_metadata.data_len() != 0 {
+        let listing_metadata_data: ListingMetadata = try_from_slice_unchecked(&listing_metadata.data.borrow())?;
+        if listing_metadata_data.vault {
+            assert_keys_equal(listing_metadata_data.seller, *owner.key)?;
+        }
+        listing_metadata_data.vault
+    }
+    else {
+        false
+    };
+
     // check NFT owned
-    let ata_data = spl_token::state::Account::unpack_from_slice(&ata_account.data.borrow())?;
-    if ata_data.amount != 1 {
-        msg!("Error: token account does not own token");
-        return Err(CustomError::MissingTokenOwner.into());
+    if !in_vault {
+        let ata_data = spl_token::state::Account::unpack_from_slice(&ata_account.data.borrow())?;
+        if ata_data.amount != 1 {
+            msg!("Error: token account does not own token");
+            return Err(CustomError::MissingTokenOwner.into());
+        }
+    }
+
+    // check listing parameters
+    if args.create && args.price == 0 {
+        msg!("Error: listing price must be positive");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    if args.create && args.expires_at != 0 && args.expires_at <= Clock::get()?.unix_timestamp {
+        msg!("Error: listing already expired");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    if args.create && args.end_timestamp != 0 {
+        if args.end_timestamp <= args.start_timestamp {
+            msg!("Error: dutch listing must end after it starts");
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        if args.end_price > args.price {
+            msg!("Error: dutch listing price cannot increase");
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        if args.end_price == 0 {
+            msg!("Error: dutch listing end price must be positive");
+            return Err(ProgramError::InvalidInstructionData);
+        }
+    }
+    if args.create && in_vault && !args.vault {
+        msg!("Error: space is in the listing vault, cancel the listing first");
+        return Err(ProgramError::InvalidInstructionData);
     }
 
     // main code
-    if args.create {
+    let mut listing_vault_key = Pubkey::default();
+    let mut bump_listing_vault = 0;
+    if (args.create && args.vault) || in_vault {
+        let listing_vault = next_account_info(account_info_iter)?;
+        let space_mint = next_account_info(account_info_iter)?;
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+
+        assert_keys_equal(rent::id(), *rent_sysvar_info.key)?;
+
+        if space_metadata_data.mint != *space_mint.key {
+            msg!("Error: space account does not match mint account");
+            return Err(CustomError::MintMismatch.into());
+        }
+
+        // verify listing vault
+        let seeds_listing_vault = &[
+            &base.key.to_bytes(),
+            LISTING_VAULT_SEED,
+            &args.space_x.to_le_bytes(),
+            &args.space_y.to_le_bytes(),
+        ];
+        let (key, bump) = Pubkey::find_program_address(seeds_listing_vault, program_id);
+        assert_keys_equal(key, *listing_vault.key)?;
+        listing_vault_key = key;
+        bump_listing_vault = bump;
+        let seeds_listing_vault = &[
+            &base.key.to_bytes(),
+            LISTING_VAULT_SEED,
+            &args.space_x.to_le_bytes(),
+            &args.space_y.to_le_bytes(),
+            &[bump_listing_vault],
+        ];
+
+        if args.create && !in_vault {
+            // create the listing vault, owned by the listing metadata
+            create_pda_account(
+                owner,
+                listing_vault,
+                system_program,
+                spl_token::state::Account::LEN,
+                token_program.key,
+                0,
+                seeds_listing_vault,
+            )?;
+            invoke(
+                &spl_token::instruction::initialize_account(
+                    token_program.key,
+                    listing_vault.key,
+                    space_mint.key,
+                    listing_metadata.key,
+                )?,
+                &[
+                    token_program.clone(),
+                    listing_vault.clone(),
+                    space_mint.clone(),
+                    listing_metadata.clone(),
+                    rent_sysvar_info.clone(),
+                ],
+            )?;
+
+            // transfer NFT from owner to listing vault
+            invoke(
+                &spl_token::instruction::transfer(
+                    token_program.key,
+                    ata_account.key,
+                    listing_vault.key,
+                    owner.key,
+                    &[],
+                    1,
+                )?,
+                &[
+                    token_program.clone(),
+                    ata_account.clone(),
+                    listing_vault.clone(),
+                    owner.clone(),
+                ],
+            )?;
+        }
+        else if !args.create {
+            let seeds_listing_metadata = &[
+                &base.key.to_bytes(),
+                LISTING_METADATA_SEED,
+                &args.space_x.to_le_bytes(),
+                &args.space_y.to_le_bytes(),
+                &[bump_listing_metadata],
+            ];
+
+            // transfer NFT from listing vault back to owner
+            invoke_signed(
+                &spl_token::instruction::transfer(
+                    token_program.key,
+                    listing_vault.key,
+                    ata_account.key,
+                    listing_metadata.key,
+                    &[],
+                    1,
+                )?,
+                &[
+                    token_program.clone(),
+                    listing_vault.clone(),
+                    ata_account.clone(),
+                    listing_metadata.clone(),
+                ],
+                &[seeds_listing_metadata],
+            )?;
+
+            // close listing vault, returning rent to owner
+            invoke_signed(
+                &spl_token::instruction::close_account(
+                    token_program.key,
+                    listing_vault.key,
+                    owner.key,
+                    listing_metadata.key,
+                    &[],
+                )?,
+                &[
+                    token_program.clone(),
+                    listing_vault.clone(),
+                    owner.clone(),
+                    listing_metadata.clone(),
+                ],
+                &[seeds_listing_metadata],
+            )?;
+        }
+    }
+    else if args.create {
         // approve delegate
         invoke(
             &spl_token::instruction::approve(
@@ -101,12 +339,59 @@ pub fn process(
     if args.create {
         // if creating sell offer, set price
         space_metadata_data.price = args.price;
+        space_metadata_data.payment_mint = args.payment_mint;
+        space_metadata_data.expires_at = args.expires_at;
+        if args.end_timestamp != 0 {
+            space_metadata_data.end_price = args.end_price;
+            space_metadata_data.start_timestamp = args.start_timestamp;
+            space_metadata_data.end_timestamp = args.end_timestamp;
+        }
+        else {
+            space_metadata_data.end_price = 0;
+            space_metadata_data.start_timestamp = 0;
+            space_metadata_data.end_timestamp = 0;
+        }
     }
     else{
-        space_metadata_data.price = 0;
+        clear_listing(&mut space_metadata_data);
     }
 
     space_metadata_data.serialize(&mut *space_metadata.data.borrow_mut())?;
 
+    // create listing metadata if necessary
+    if args.create && listing_metadata.data_len() == 0 {
+        create_pda_account(
+            owner,
+            listing_metadata,
+            system_program,
+            LISTING_METADATA_RESERVE,
+            program_id,
+            0,
+            seeds_listing_metadata,
+        )?;
+    }
+
+    // write to listing metadata
+    if listing_metadata.data_len() != 0 {
+        let mut listing_metadata_data: ListingMetadata = try_from_slice_unchecked(&listing_metadata.data.borrow_mut())?;
+        listing_metadata_data.bump = bump_listing_metadata;
+        if args.create && args.vault {
+            listing_metadata_data.buyer = args.buyer;
+            listing_metadata_data.seller = *owner.key;
+            listing_metadata_data.token_account = listing_vault_key;
+            listing_metadata_data.vault = true;
+            listing_metadata_data.vault_bump = bump_listing_vault;
+        }
+        else if args.create {
+            listing_metadata_data.buyer = args.buyer;
+            listing_metadata_data.seller = *owner.key;
+            listing_metadata_data.token_account = *ata_account.key;
+        }
+        else {
+            clear_listing_metadata(&mut listing_metadata_data);
+        }
+        listing_metadata_data.serialize(&mut *listing_metadata.data.borrow_mut())?;
+    }
+
     Ok(())
 }
@@ -0,0 +1,52 @@
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    instruction::ChangeOffersArgs,
+    processor::change_offer::change_offer,
+};
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &ChangeOffersArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let base = next_account_info(account_info_iter)?;
+    let owner = next_account_info(account_info_iter)?;
+    let token_program = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
+
+    if args.offers.is_empty() {
+        msg!("Error: no offers to change");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    for offer in args.offers.iter() {
+        let space_metadata = next_account_info(account_info_iter)?;
+        let ata_account = next_account_info(account_info_iter)?;
+        let sell_delegate = next_account_info(account_info_iter)?;
+        let listing_metadata = next_account_info(account_info_iter)?;
+
+        change_offer(
+            program_id,
+            base,
+            owner,
+            token_program,
+            system_program,
+            space_metadata,
+            ata_account,
+            sell_delegate,
+            listing_metadata,
+            account_info_iter,
+            offer,
+        )?;
+    }
+
+    Ok(())
+}
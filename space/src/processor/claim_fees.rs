@@ -0,0 +1,74 @@
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    borsh::try_from_slice_unchecked,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{Sysvar, rent::Rent},
+};
+
+use crate::{
+    instruction::ClaimFeesArgs,
+    processor::processor_utils::transfer_lamports,
+    state::{
+        FEE_VAULT_SEED,
+        NEIGHBORHOOD_METADATA_SEED,
+        FeeVault,
+        NeighborhoodMetadata,
+    },
+    validation_utils::assert_keys_equal,
+};
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &ClaimFeesArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let base = next_account_info(account_info_iter)?;
+    let neighborhood_metadata = next_account_info(account_info_iter)?;
+    let fee_vault = next_account_info(account_info_iter)?;
+    let neighborhood_creator = next_account_info(account_info_iter)?;
+    let destination = next_account_info(account_info_iter)?;
+
+    if !neighborhood_creator.is_signer {
+        msg!("Error: Missing signer");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // deserialize and verify neighborhood metadata
+    let neighborhood_metadata_data: NeighborhoodMetadata = try_from_slice_unchecked(&neighborhood_metadata.data.borrow())?;
+    let seeds_neighborhood_metadata = &[
+        &base.key.to_bytes(),
+        NEIGHBORHOOD_METADATA_SEED,
+        &args.neighborhood_x.to_le_bytes(),
+        &args.neighborhood_y.to_le_bytes(),
+        &[neighborhood_metadata_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_neighborhood_metadata, program_id)?;
+    assert_keys_equal(key, *neighborhood_metadata.key)?;
+
+    // check that signer is creator of neighborhood
+    assert_keys_equal(neighborhood_metadata_data.creator, *neighborhood_creator.key)?;
+
+    // deserialize and verify fee vault
+    let fee_vault_data: FeeVault = try_from_slice_unchecked(&fee_vault.data.borrow())?;
+    let seeds_fee_vault = &[
+        &base.key.to_bytes(),
+        FEE_VAULT_SEED,
+        &args.neighborhood_x.to_le_bytes(),
+        &args.neighborhood_y.to_le_bytes(),
+        &[fee_vault_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_fee_vault, program_id)?;
+    assert_keys_equal(key, *fee_vault.key)?;
+
+    // withdraw everything above the vault's rent exemption
+    let claimable = fee_vault.lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(fee_vault.data_len()));
+    msg!("claiming {} of {} lifetime fees", claimable, fee_vault_data.lifetime_fees);
+    transfer_lamports(fee_vault, destination, claimable)?;
+
+    Ok(())
+}
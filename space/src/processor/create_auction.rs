@@ -0,0 +1,213 @@
+use borsh::{BorshSerialize};
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    borsh::try_from_slice_unchecked,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    system_program,
+    sysvar::{Sysvar, rent},
+};
+use spl_token;
+
+use crate::{
+    error::CustomError,
+    instruction::CreateAuctionArgs,
+    processor::processor_utils::{assert_not_paused, clear_listing, create_pda_account},
+    state::{
+        AUCTION_SEED,
+        AUCTION_VAULT_SEED,
+        AUCTION_RESERVE,
+        SPACE_METADATA_SEED,
+        Base,
+        Auction,
+        SpaceMetadata,
+    },
+    validation_utils::{assert_is_ata, assert_keys_equal, assert_owned_by},
+};
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &CreateAuctionArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let base = next_account_info(account_info_iter)?;
+    let space_metadata = next_account_info(account_info_iter)?;
+    let space_mint = next_account_info(account_info_iter)?;
+    let auction = next_account_info(account_info_iter)?;
+    let auction_vault = next_account_info(account_info_iter)?;
+    let seller = next_account_info(account_info_iter)?;
+    let seller_ata_space = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
+    let token_program = next_account_info(account_info_iter)?;
+    let rent_sysvar_info = next_account_info(account_info_iter)?;
+
+    if !seller.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // check world is not paused
+    assert_owned_by(base, program_id)?;
+    let base_data: Base = try_from_slice_unchecked(&base.data.borrow())?;
+    assert_not_paused(&base_data)?;
+
+    // check programs
+    assert_keys_equal(system_program::id(), *system_program.key)?;
+    assert_keys_equal(spl_token::id(), *token_program.key)?;
+    assert_keys_equal(rent::id(), *rent_sysvar_info.key)?;
+
+    if args.duration <= 0 {
+        msg!("Error: auction duration must be positive");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // deserialize and verify space metadata
+    let mut space_metadata_data: SpaceMetadata = try_from_slice_unchecked(&space_metadata.data.borrow_mut())?;
+
+    if space_metadata_data.mint != *space_mint.key {
+        msg!("Error: space account does not match mint account");
+        return Err(CustomError::MintMismatch.into());
+    }
+
+    let seeds_space_metadata = &[
+        &base.key.to_bytes(),
+        SPACE_METADATA_SEED,
+        &args.space_x.to_le_bytes(),
+        &args.space_y.to_le_bytes(),
+        &[space_metadata_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_space_metadata, program_id)?;
+    assert_keys_equal(key, *space_metadata.key)?;
+
+    // check ATAs
+    assert_is_ata(seller_ata_space, seller.key, space_mint.key)?;
+
+    // check NFT owned
+    let seller_ata_space_data = spl_token::state::Account::unpack(&seller_ata_space.data.borrow())?;
+    if seller_ata_space_data.amount != 1 {
+        msg!("Error: token account does not own token");
+        return Err(CustomError::MissingTokenOwner.into());
+    }
+
+    // verify auction account
+    let seeds_auction = &[
+        &base.key.to_bytes(),
+        AUCTION_SEED,
+        &args.space_x.to_le_bytes(),
+        &args.space_y.to_le_bytes(),
+    ];
+    let (key, bump_auction) = Pubkey::find_program_address(seeds_auction, program_id);
+    assert_keys_equal(key, *auction.key)?;
+    let seeds_auction = &[
+        &base.key.to_bytes(),
+        AUCTION_SEED,
+        &args.space_x.to_le_bytes(),
+        &args.space_y.to_le_bytes(),
+        &[bump_auction],
+    ];
+
+    // verify auction vault
+    let seeds_auction_vault = &[
+        &base.key.to_bytes(),
+        AUCTION_VAULT_SEED,
+        &args.space_x.to_le_bytes(),
+        &args.space_y.to_le_bytes(),
+    ];
+    let (key, bump_auction_vault) = Pubkey::find_program_address(seeds_auction_vault, program_id);
+    assert_keys_equal(key, *auction_vault.key)?;
+    let seeds_auction_vault = &[
+        &base.key.to_bytes(),
+        AUCTION_VAULT_SEED,
+        &args.space_x.to_le_bytes(),
+        &args.space_y.to_le_bytes(),
+        &[bump_auction_vault],
+    ];
+
+    // check auction is not running
+    if auction.data_len() != 0 {
+        msg!("Error: auction already running");
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    // create the auction account
+    create_pda_account(
+        seller,
+        auction,
+        system_program,
+        AUCTION_RESERVE,
+        program_id,
+        0,
+        seeds_auction,
+    )?;
+
+    // create the auction vault, owned by the auction account
+    create_pda_account(
+        seller,
+        auction_vault,
+        system_program,
+        spl_token::state::Account::LEN,
+        token_program.key,
+        0,
+        seeds_auction_vault,
+    )?;
+    invoke(
+        &spl_token::instruction::initialize_account(
+            token_program.key,
+            auction_vault.key,
+            space_mint.key,
+            auction.key,
+        )?,
+        &[
+            token_program.clone(),
+            auction_vault.clone(),
+            space_mint.clone(),
+            auction.clone(),
+            rent_sysvar_info.clone(),
+        ],
+    )?;
+
+    // transfer NFT from seller to auction vault
+    invoke(
+        &spl_token::instruction::transfer(
+            token_program.key,
+            seller_ata_space.key,
+            auction_vault.key,
+            seller.key,
+            &[],
+            1,
+        )?,
+        &[
+            token_program.clone(),
+            seller_ata_space.clone(),
+            auction_vault.clone(),
+            seller.clone(),
+        ],
+    )?;
+
+    // write to auction
+    let now_ts = Clock::get()?.unix_timestamp;
+    let mut auction_data: Auction = try_from_slice_unchecked(&auction.data.borrow_mut())?;
+    auction_data.bump = bump_auction;
+    auction_data.vault_bump = bump_auction_vault;
+    auction_data.seller = *seller.key;
+    auction_data.mint = *space_mint.key;
+    auction_data.space_x = args.space_x;
+    auction_data.space_y = args.space_y;
+    auction_data.reserve_price = args.reserve_price;
+    auction_data.min_increment = args.min_increment;
+    auction_data.end_timestamp = now_ts
+        .checked_add(args.duration)
+        .ok_or(CustomError::NumericalOverflow)?;
+    auction_data.serialize(&mut *auction.data.borrow_mut())?;
+
+    // the space is in the vault, so any sell offer is void
+    clear_listing(&mut space_metadata_data);
+    space_metadata_data.serialize(&mut *space_metadata.data.borrow_mut())?;
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    borsh::try_from_slice_unchecked,
+    entrypoint::ProgramResult,
+    program::invoke,
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+};
+use spl_token;
+
+use crate::{
+    instruction::DelistBundleArgs,
+    processor::processor_utils::{close_account, get_bundle_spaces},
+    state::{
+        BUNDLE_SEED,
+        Bundle,
+    },
+    validation_utils::{assert_keys_equal, assert_owned_by},
+};
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &DelistBundleArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let base = next_account_info(account_info_iter)?;
+    let seller = next_account_info(account_info_iter)?;
+    let bundle = next_account_info(account_info_iter)?;
+    let token_program = next_account_info(account_info_iter)?;
+
+    if !seller.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // check programs
+    assert_keys_equal(spl_token::id(), *token_program.key)?;
+
+    // deserialize and verify bundle account
+    let bundle_data: Bundle = try_from_slice_unchecked(&bundle.data.borrow())?;
+    let seeds_bundle = &[
+        &base.key.to_bytes(),
+        BUNDLE_SEED,
+        &args.min_x.to_le_bytes(),
+        &args.min_y.to_le_bytes(),
+        &args.max_x.to_le_bytes(),
+        &args.max_y.to_le_bytes(),
+        &[bundle_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_bundle, program_id)?;
+    assert_keys_equal(key, *bundle.key)?;
+    assert_keys_equal(bundle_data.seller, *seller.key)?;
+
+    // revoke bundle delegate on every space still approved to it
+    let spaces = get_bundle_spaces(args.min_x, args.min_y, args.max_x, args.max_y)?;
+    for _ in spaces {
+        let seller_ata_space = next_account_info(account_info_iter)?;
+        assert_owned_by(seller_ata_space, &spl_token::id())?;
+        let seller_ata_space_data = spl_token::state::Account::unpack(&seller_ata_space.data.borrow())?;
+        if seller_ata_space_data.owner != *seller.key || !seller_ata_space_data.delegate.contains(bundle.key) {
+            continue;
+        }
+        invoke(
+            &spl_token::instruction::revoke(token_program.key, seller_ata_space.key, seller.key, &[])?,
+            &[token_program.clone(), seller_ata_space.clone(), seller.clone()],
+        )?;
+    }
+
+    // return bundle account rent to seller
+    close_account(bundle, seller)?;
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use borsh::{BorshSerialize};
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    borsh::try_from_slice_unchecked,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+use std::cmp;
+
+use crate::{
+    error::CustomError,
+    instruction::ExecuteAdminActionArgs,
+    processor::processor_utils::{apply_admin_action, close_account},
+    state::{
+        ADMIN_ACTION_SEED,
+        Base,
+        QueuedAdminAction,
+    },
+    validation_utils::{assert_keys_equal, assert_owned_by},
+};
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &ExecuteAdminActionArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let base = next_account_info(account_info_iter)?;
+    let authority = next_account_info(account_info_iter)?;
+    let queued_action = next_account_info(account_info_iter)?;
+
+    assert_owned_by(base, program_id)?;
+    let mut base_data: Base = try_from_slice_unchecked(&base.data.borrow_mut())?;
+
+    if !authority.is_signer {
+        msg!("Error: Missing signer");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // check that signer is authority of base
+    assert_keys_equal(base_data.authority, *authority.key)?;
+
+    // deserialize and verify queued action
+    assert_owned_by(queued_action, program_id)?;
+    let queued_action_data: QueuedAdminAction = try_from_slice_unchecked(&queued_action.data.borrow())?;
+    let seeds_queued_action = &[
+        &base.key.to_bytes(),
+        ADMIN_ACTION_SEED,
+        &args.id.to_le_bytes(),
+        &[queued_action_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_queued_action, program_id)?;
+    assert_keys_equal(key, *queued_action.key)?;
+
+    // check delay has passed, including any increase of the delay since the action was queued
+    let executable_at = cmp::max(
+        queued_action_data.eta,
+        queued_action_data.queued_at.saturating_add(base_data.admin_delay),
+    );
+    if Clock::get()?.unix_timestamp < executable_at {
+        msg!("Error: admin action is not executable until {}", executable_at);
+        return Err(CustomError::Timelocked.into());
+    }
+
+    // write to base
+    apply_admin_action(&mut base_data, &queued_action_data.action)?;
+    base_data.serialize(&mut *base.data.borrow_mut())?;
+
+    // close queued action, returning rent to authority
+    close_account(queued_action, authority)?;
+
+    Ok(())
+}
@@ -0,0 +1,171 @@
+use borsh::{BorshSerialize};
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    borsh::try_from_slice_unchecked,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+    sysvar::{Sysvar, rent},
+};
+use spl_associated_token_account;
+use spl_token;
+
+use crate::{
+    error::CustomError,
+    instruction::ExpireOfferArgs,
+    processor::processor_utils::{clear_listing, clear_listing_metadata, create_ata_if_needed},
+    state::{
+        LISTING_METADATA_SEED,
+        LISTING_VAULT_SEED,
+        SPACE_METADATA_SEED,
+        ListingMetadata,
+        SpaceMetadata,
+    },
+    validation_utils::{assert_is_ata, assert_keys_equal},
+};
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &ExpireOfferArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let base = next_account_info(account_info_iter)?;
+    let space_metadata = next_account_info(account_info_iter)?;
+    let listing_metadata = next_account_info(account_info_iter)?;
+
+    // deserialize and verify space metadata
+    let mut space_metadata_data: SpaceMetadata = try_from_slice_unchecked(&space_metadata.data.borrow_mut())?;
+    let seeds_space_metadata = &[
+        &base.key.to_bytes(),
+        SPACE_METADATA_SEED,
+        &args.space_x.to_le_bytes(),
+        &args.space_y.to_le_bytes(),
+        &[space_metadata_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_space_metadata, program_id)?;
+    assert_keys_equal(key, *space_metadata.key)?;
+
+    // verify listing metadata
+    let seeds_listing_metadata = &[
+        &base.key.to_bytes(),
+        LISTING_METADATA_SEED,
+        &args.space_x.to_le_bytes(),
+        &args.space_y.to_le_bytes(),
+    ];
+    let (key, bump_listing_metadata) = Pubkey::find_program_address(seeds_listing_metadata, program_id);
+    assert_keys_equal(key, *listing_metadata.key)?;
+    let seeds_listing_metadata = &[
+        &base.key.to_bytes(),
+        LISTING_METADATA_SEED,
+        &args.space_x.to_le_bytes(),
+        &args.space_y.to_le_bytes(),
+        &[bump_listing_metadata],
+    ];
+
+    // check listing has expired
+    let now_ts = Clock::get()?.unix_timestamp;
+    if space_metadata_data.expires_at == 0 || now_ts < space_metadata_data.expires_at {
+        msg!("Error: listing has not expired");
+        return Err(CustomError::InvalidListing.into());
+    }
+
+    // return a vaulted space to the seller and close the listing vault
+    if listing_metadata.data_len() != 0 {
+        let mut listing_metadata_data: ListingMetadata = try_from_slice_unchecked(&listing_metadata.data.borrow())?;
+        if listing_metadata_data.vault {
+            let payer = next_account_info(account_info_iter)?;
+            let seller = next_account_info(account_info_iter)?;
+            let seller_ata_space = next_account_info(account_info_iter)?;
+            let listing_vault = next_account_info(account_info_iter)?;
+            let space_mint = next_account_info(account_info_iter)?;
+            let system_program_info = next_account_info(account_info_iter)?;
+            let token_program = next_account_info(account_info_iter)?;
+            let associated_token_program = next_account_info(account_info_iter)?;
+            let rent_program = next_account_info(account_info_iter)?;
+
+            // check programs
+            assert_keys_equal(system_program::id(), *system_program_info.key)?;
+            assert_keys_equal(spl_token::id(), *token_program.key)?;
+            assert_keys_equal(spl_associated_token_account::id(), *associated_token_program.key)?;
+            assert_keys_equal(rent::id(), *rent_program.key)?;
+
+            if !payer.is_signer {
+                msg!("Error: Missing signer");
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+
+            if space_metadata_data.mint != *space_mint.key {
+                msg!("Error: space account does not match mint account");
+                return Err(CustomError::MintMismatch.into());
+            }
+
+            // verify listing vault and seller
+            let seeds_listing_vault: &[&[u8]] = &[
+                &base.key.to_bytes(),
+                LISTING_VAULT_SEED,
+                &args.space_x.to_le_bytes(),
+                &args.space_y.to_le_bytes(),
+                &[listing_metadata_data.vault_bump],
+            ];
+            let key = Pubkey::create_program_address(seeds_listing_vault, program_id)?;
+            assert_keys_equal(key, *listing_vault.key)?;
+            assert_keys_equal(listing_metadata_data.seller, *seller.key)?;
+
+            // create seller ATA if necessary
+            create_ata_if_needed(payer, seller, space_mint, seller_ata_space, system_program_info, token_program, rent_program, associated_token_program)?;
+            assert_is_ata(seller_ata_space, seller.key, space_mint.key)?;
+
+            // transfer NFT from listing vault back to seller
+            invoke_signed(
+                &spl_token::instruction::transfer(
+                    token_program.key,
+                    listing_vault.key,
+                    seller_ata_space.key,
+                    listing_metadata.key,
+                    &[],
+                    1,
+                )?,
+                &[
+                    token_program.clone(),
+                    listing_vault.clone(),
+                    seller_ata_space.clone(),
+                    listing_metadata.clone(),
+                ],
+                &[seeds_listing_metadata],
+            )?;
+
+            // close listing vault, returning rent to seller
+            invoke_signed(
+                &spl_token::instruction::close_account(
+                    token_program.key,
+                    listing_vault.key,
+                    seller.key,
+                    listing_metadata.key,
+                    &[],
+                )?,
+                &[
+                    token_program.clone(),
+                    listing_vault.clone(),
+                    seller.clone(),
+                    listing_metadata.clone(),
+                ],
+                &[seeds_listing_metadata],
+            )?;
+        }
+
+        // write to listing metadata
+        clear_listing_metadata(&mut listing_metadata_data);
+        listing_metadata_data.serialize(&mut *listing_metadata.data.borrow_mut())?;
+    }
+
+    // write to space data
+    clear_listing(&mut space_metadata_data);
+    space_metadata_data.serialize(&mut *space_metadata.data.borrow_mut())?;
+
+    Ok(())
+}
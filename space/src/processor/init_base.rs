@@ -15,6 +15,9 @@ use crate::{
     instruction::InitBaseArgs,
     state::{
         BASE_RESERVE,
+        DEFAULT_MARKETPLACE_FEE_BPS,
+        DEFAULT_MAX_NEIGHBORHOOD_FEE_BPS,
+        INITIAL_FREE_NEIGHBORHOODS,
         NEIGHBORHOOD_LIST_SEED,
         NEIGHBORHOOD_LIST_RESERVE,
         Base,
@@ -104,7 +107,9 @@ pub fn process(
     let mut base_data: Base = try_from_slice_unchecked(&base.data.borrow_mut())?;
     base_data.neighborhood_count = 0;
     base_data.authority = *payer.key;
-    base_data.authority_privileges = true;
+    base_data.free_neighborhoods = INITIAL_FREE_NEIGHBORHOODS;
+    base_data.marketplace_fee_bps = Some(DEFAULT_MARKETPLACE_FEE_BPS);
+    base_data.max_neighborhood_fee_bps = Some(DEFAULT_MAX_NEIGHBORHOOD_FEE_BPS);
     base_data.serialize(&mut *base.data.borrow_mut())?;
 
     let mut neighborhood_list_data: NeighborhoodList = try_from_slice_unchecked(&neighborhood_list.data.borrow_mut())?;
@@ -17,7 +17,7 @@ use spl_token;
 
 use crate::{
     instruction::InitNeighborhoodMetadataArgs,
-    processor::processor_utils::{get_neighborhood_creation_price, get_space_xy_from_name, get_neighborhood_xy},
+    processor::processor_utils::{assert_in_world_bounds, get_neighborhood_creation_price, get_neighborhood_pricing, get_space_xy_from_name, get_neighborhood_xy, migrate_authority_privileges},
     state::{
         EXTEND_TOKEN_MINT,
         NEIGHBORHOOD_METADATA_SEED,
@@ -27,7 +27,7 @@ use crate::{
         NeighborhoodMetadata,
         NeighborhoodList,
     },
-    validation_utils::{assert_keys_equal, assert_is_ata},
+    validation_utils::{assert_keys_equal, assert_is_ata, assert_owned_by},
 };
 
 pub fn process(
@@ -98,9 +98,18 @@ pub fn process(
     }
 
     let mut base_data: Base = try_from_slice_unchecked(&base.data.borrow_mut())?;
+    migrate_authority_privileges(&mut base_data);
 
-    // charge if not original creator during price exempt period
-    if !((base_data.authority_privileges) && (*creator.key == base_data.authority)) {
+    // check neighborhood is inside the world
+    assert_in_world_bounds(&base_data, args.neighborhood_x, args.neighborhood_y)?;
+
+    // free for the authority and allow-listed creators while the base has free neighborhoods left
+    let free = base_data.free_neighborhoods > 0
+        && (*creator.key == base_data.authority || base_data.free_creators.contains(creator.key));
+    if free {
+        base_data.free_neighborhoods -= 1;
+    }
+    else {
         // check payment mint matches extend token
         assert_keys_equal(*extend_token_mint.key, Pubkey::from_str(EXTEND_TOKEN_MINT).unwrap())?;
         
@@ -108,7 +117,8 @@ pub fn process(
         assert_is_ata(creator_ata_extend, creator.key, extend_token_mint.key)?;
 
         // check price    
-        if args.price != get_neighborhood_creation_price(args.neighborhood_x, args.neighborhood_y) {
+        let price = get_neighborhood_creation_price(&get_neighborhood_pricing(&base_data), args.neighborhood_x, args.neighborhood_y)?;
+        if args.price != price {
             msg!("Error: price invalid");
             return Err(ProgramError::MissingRequiredSignature); 
         }
@@ -130,8 +140,10 @@ pub fn process(
                 creator.clone(),
             ],
         )?;
+    }
 
-        // check creator of neighborhood matches candymachine creator
+    // check creator of neighborhood matches candymachine creator, unless the authority creates it for free
+    if !(free && *creator.key == base_data.authority) {
         let auth = Pubkey::new(&candymachine_config.data.borrow()[8..40]);
         assert_keys_equal(auth, *creator.key)?;
     }
@@ -142,6 +154,37 @@ pub fn process(
         return Err(ProgramError::AccountAlreadyInitialized);
     }
 
+    // check new neighborhood borders an existing one, the origin is exempt
+    if base_data.require_adjacent && (args.neighborhood_x, args.neighborhood_y) != (0, 0) {
+        let neighbor_metadata = next_account_info(account_info_iter)?;
+        assert_owned_by(neighbor_metadata, program_id)?;
+        let neighbor_metadata_data: NeighborhoodMetadata = try_from_slice_unchecked(&neighbor_metadata.data.borrow())?;
+
+        let neighbors = [
+            (args.neighborhood_x.checked_add(1), Some(args.neighborhood_y)),
+            (args.neighborhood_x.checked_sub(1), Some(args.neighborhood_y)),
+            (Some(args.neighborhood_x), args.neighborhood_y.checked_add(1)),
+            (Some(args.neighborhood_x), args.neighborhood_y.checked_sub(1)),
+        ];
+        let adjacent = neighbors.iter().any(|neighbor| match *neighbor {
+            (Some(neighbor_x), Some(neighbor_y)) => {
+                let seeds_neighbor_metadata = &[
+                    &base.key.to_bytes(),
+                    NEIGHBORHOOD_METADATA_SEED,
+                    &neighbor_x.to_le_bytes(),
+                    &neighbor_y.to_le_bytes(),
+                    &[neighbor_metadata_data.bump],
+                ];
+                Pubkey::create_program_address(seeds_neighbor_metadata, program_id) == Ok(*neighbor_metadata.key)
+            }
+            _ => false,
+        });
+        if !adjacent {
+            msg!("Error: neighborhood must border an existing neighborhood");
+            return Err(ProgramError::InvalidArgument);
+        }
+    }
+
     // create the neighborhood metadata account
     let required_lamports = rent
         .minimum_balance(NEIGHBORHOOD_METADATA_RESERVE)
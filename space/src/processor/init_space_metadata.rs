@@ -16,15 +16,16 @@ use solana_program::{
 use crate::{
     error::CustomError,
     instruction::InitSpaceMetadataArgs,
-    processor::processor_utils::{get_neighborhood_xy, get_space_xy_from_name},
+    processor::processor_utils::{assert_in_world_bounds, get_neighborhood_xy, get_space_xy_from_name},
     state::{
         NEIGHBORHOOD_METADATA_SEED,
         SPACE_METADATA_SEED,
         SPACE_METADATA_RESERVE,
+        Base,
         NeighborhoodMetadata,
         SpaceMetadata,
     },
-    validation_utils::{assert_is_ata, assert_keys_equal},
+    validation_utils::{assert_is_ata, assert_keys_equal, assert_owned_by},
 };
 
 pub fn process(
@@ -50,9 +51,14 @@ pub fn process(
     // check programs
     assert_keys_equal(system_program::id(), *system_program.key)?;
 
+    // check space is inside the world
+    let (neighborhood_x, neighborhood_y) = get_neighborhood_xy(args.space_x, args.space_y);
+    assert_owned_by(base, program_id)?;
+    let base_data: Base = try_from_slice_unchecked(&base.data.borrow())?;
+    assert_in_world_bounds(&base_data, neighborhood_x, neighborhood_y)?;
+
     // deserialize and verify neighborhood metadata
     let neighborhood_metadata_data: NeighborhoodMetadata = try_from_slice_unchecked(&neighborhood_metadata.data.borrow())?;
-    let (neighborhood_x, neighborhood_y) = get_neighborhood_xy(args.space_x, args.space_y);
     let seeds_neighborhood_metadata = &[
         &base.key.to_bytes(),
         NEIGHBORHOOD_METADATA_SEED,
@@ -0,0 +1,156 @@
+use borsh::{BorshSerialize};
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    borsh::try_from_slice_unchecked,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    system_program,
+};
+use spl_token;
+
+use crate::{
+    error::CustomError,
+    instruction::ListBundleArgs,
+    processor::processor_utils::{assert_not_paused, clear_listing, create_pda_account, get_bundle_spaces},
+    state::{
+        BUNDLE_SEED,
+        BUNDLE_RESERVE,
+        SPACE_METADATA_SEED,
+        Base,
+        Bundle,
+        SpaceMetadata,
+    },
+    validation_utils::{assert_is_ata, assert_keys_equal, assert_owned_by},
+};
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &ListBundleArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let base = next_account_info(account_info_iter)?;
+    let seller = next_account_info(account_info_iter)?;
+    let bundle = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
+    let token_program = next_account_info(account_info_iter)?;
+
+    if !seller.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // check world is not paused
+    assert_owned_by(base, program_id)?;
+    let base_data: Base = try_from_slice_unchecked(&base.data.borrow())?;
+    assert_not_paused(&base_data)?;
+
+    // check programs
+    assert_keys_equal(system_program::id(), *system_program.key)?;
+    assert_keys_equal(spl_token::id(), *token_program.key)?;
+
+    if args.price == 0 {
+        msg!("Error: listing price must be positive");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let spaces = get_bundle_spaces(args.min_x, args.min_y, args.max_x, args.max_y)?;
+
+    // verify bundle account
+    let seeds_bundle = &[
+        &base.key.to_bytes(),
+        BUNDLE_SEED,
+        &args.min_x.to_le_bytes(),
+        &args.min_y.to_le_bytes(),
+        &args.max_x.to_le_bytes(),
+        &args.max_y.to_le_bytes(),
+    ];
+    let (key, bump_bundle) = Pubkey::find_program_address(seeds_bundle, program_id);
+    assert_keys_equal(key, *bundle.key)?;
+    let seeds_bundle = &[
+        &base.key.to_bytes(),
+        BUNDLE_SEED,
+        &args.min_x.to_le_bytes(),
+        &args.min_y.to_le_bytes(),
+        &args.max_x.to_le_bytes(),
+        &args.max_y.to_le_bytes(),
+        &[bump_bundle],
+    ];
+
+    for (space_x, space_y) in spaces {
+        let space_metadata = next_account_info(account_info_iter)?;
+        let seller_ata_space = next_account_info(account_info_iter)?;
+
+        // deserialize and verify space metadata
+        let mut space_metadata_data: SpaceMetadata = try_from_slice_unchecked(&space_metadata.data.borrow())?;
+        let seeds_space_metadata = &[
+            &base.key.to_bytes(),
+            SPACE_METADATA_SEED,
+            &space_x.to_le_bytes(),
+            &space_y.to_le_bytes(),
+            &[space_metadata_data.bump],
+        ];
+        let key = Pubkey::create_program_address(seeds_space_metadata, program_id)?;
+        assert_keys_equal(key, *space_metadata.key)?;
+
+        // check ATAs
+        assert_is_ata(seller_ata_space, seller.key, &space_metadata_data.mint)?;
+
+        // check NFT owned
+        let seller_ata_space_data = spl_token::state::Account::unpack(&seller_ata_space.data.borrow())?;
+        if seller_ata_space_data.amount != 1 {
+            msg!("Error: token account does not own token");
+            return Err(CustomError::MissingTokenOwner.into());
+        }
+
+        // approve bundle as delegate
+        invoke(
+            &spl_token::instruction::approve(
+                token_program.key,
+                seller_ata_space.key,
+                bundle.key,
+                seller.key,
+                &[],
+                1,
+            )?,
+            &[
+                token_program.clone(),
+                seller_ata_space.clone(),
+                bundle.clone(),
+                seller.clone(),
+            ],
+        )?;
+
+        // the bundle replaces any sell offer for the space
+        clear_listing(&mut space_metadata_data);
+        space_metadata_data.serialize(&mut *space_metadata.data.borrow_mut())?;
+    }
+
+    // create bundle account if necessary
+    if bundle.data_len() == 0 {
+        create_pda_account(
+            seller,
+            bundle,
+            system_program,
+            BUNDLE_RESERVE,
+            program_id,
+            0,
+            seeds_bundle,
+        )?;
+    }
+
+    // write to bundle
+    let mut bundle_data: Bundle = try_from_slice_unchecked(&bundle.data.borrow())?;
+    bundle_data.bump = bump_bundle;
+    bundle_data.seller = *seller.key;
+    bundle_data.min_x = args.min_x;
+    bundle_data.min_y = args.min_y;
+    bundle_data.max_x = args.max_x;
+    bundle_data.max_y = args.max_y;
+    bundle_data.price = args.price;
+    bundle_data.serialize(&mut *bundle.data.borrow_mut())?;
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use borsh::{BorshSerialize};
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    borsh::try_from_slice_unchecked,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_instruction, system_program,
+    sysvar::Sysvar,
+};
+
+use crate::{
+    error::CustomError,
+    instruction::PlaceAuctionBidArgs,
+    processor::processor_utils::{assert_not_paused, transfer_lamports},
+    state::{
+        AUCTION_SEED,
+        Base,
+        Auction,
+    },
+    validation_utils::{assert_keys_equal, assert_owned_by},
+};
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &PlaceAuctionBidArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let base = next_account_info(account_info_iter)?;
+    let auction = next_account_info(account_info_iter)?;
+    let bidder = next_account_info(account_info_iter)?;
+    let previous_bidder = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
+
+    if !bidder.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // check world is not paused
+    assert_owned_by(base, program_id)?;
+    let base_data: Base = try_from_slice_unchecked(&base.data.borrow())?;
+    assert_not_paused(&base_data)?;
+
+    // check programs
+    assert_keys_equal(system_program::id(), *system_program.key)?;
+
+    // deserialize and verify auction account
+    let mut auction_data: Auction = try_from_slice_unchecked(&auction.data.borrow())?;
+    let seeds_auction = &[
+        &base.key.to_bytes(),
+        AUCTION_SEED,
+        &args.space_x.to_le_bytes(),
+        &args.space_y.to_le_bytes(),
+        &[auction_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_auction, program_id)?;
+    assert_keys_equal(key, *auction.key)?;
+
+    // ensure auction still running
+    let now_ts = Clock::get()?.unix_timestamp;
+    if now_ts >= auction_data.end_timestamp {
+        msg!("Error: auction has ended");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // ensure bid is high enough
+    if auction_data.highest_bid == 0 {
+        if args.amount == 0 || args.amount < auction_data.reserve_price {
+            msg!("Error: bid is below the reserve price");
+            return Err(ProgramError::InvalidInstructionData);
+        }
+    }
+    else {
+        let min_bid = auction_data.highest_bid
+            .checked_add(auction_data.min_increment.max(1))
+            .ok_or(CustomError::NumericalOverflow)?;
+        if args.amount < min_bid {
+            msg!("Error: bid must be at least {}", min_bid);
+            return Err(ProgramError::InvalidInstructionData);
+        }
+    }
+
+    // escrow the new bid
+    invoke(
+        &system_instruction::transfer(
+            bidder.key,
+            auction.key,
+            args.amount,
+        ),
+        &[
+            bidder.clone(),
+            auction.clone(),
+            system_program.clone(),
+        ],
+    )?;
+
+    // refund the previous highest bidder
+    if auction_data.highest_bid > 0 {
+        assert_keys_equal(auction_data.highest_bidder, *previous_bidder.key)?;
+        transfer_lamports(auction, previous_bidder, auction_data.highest_bid)?;
+    }
+
+    // write to auction
+    auction_data.highest_bid = args.amount;
+    auction_data.highest_bidder = *bidder.key;
+    auction_data.serialize(&mut *auction.data.borrow_mut())?;
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+use borsh::{BorshSerialize};
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    borsh::try_from_slice_unchecked,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_instruction, system_program,
+};
+
+use crate::{
+    instruction::PlaceBidArgs,
+    processor::processor_utils::{assert_not_paused, create_pda_account, transfer_lamports},
+    state::{
+        BID_SEED,
+        BID_RESERVE,
+        SPACE_METADATA_SEED,
+        Base,
+        Bid,
+        SpaceMetadata,
+    },
+    validation_utils::{assert_keys_equal, assert_owned_by},
+};
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &PlaceBidArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let base = next_account_info(account_info_iter)?;
+    let space_metadata = next_account_info(account_info_iter)?;
+    let bid = next_account_info(account_info_iter)?;
+    let bidder = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
+
+    if !bidder.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // check world is not paused
+    assert_owned_by(base, program_id)?;
+    let base_data: Base = try_from_slice_unchecked(&base.data.borrow())?;
+    assert_not_paused(&base_data)?;
+
+    // check programs
+    assert_keys_equal(system_program::id(), *system_program.key)?;
+
+    if args.price == 0 {
+        msg!("Error: bid price must be positive");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // deserialize and verify space metadata
+    let space_metadata_data: SpaceMetadata = try_from_slice_unchecked(&space_metadata.data.borrow())?;
+    let seeds_space_metadata = &[
+        &base.key.to_bytes(),
+        SPACE_METADATA_SEED,
+        &args.space_x.to_le_bytes(),
+        &args.space_y.to_le_bytes(),
+        &[space_metadata_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_space_metadata, program_id)?;
+    assert_keys_equal(key, *space_metadata.key)?;
+
+    // verify bid account
+    let seeds_bid = &[
+        &base.key.to_bytes(),
+        BID_SEED,
+        &args.space_x.to_le_bytes(),
+        &args.space_y.to_le_bytes(),
+        &bidder.key.to_bytes(),
+    ];
+    let (key, bump_bid) = Pubkey::find_program_address(seeds_bid, program_id);
+    assert_keys_equal(key, *bid.key)?;
+    let seeds_bid = &[
+        &base.key.to_bytes(),
+        BID_SEED,
+        &args.space_x.to_le_bytes(),
+        &args.space_y.to_le_bytes(),
+        &bidder.key.to_bytes(),
+        &[bump_bid],
+    ];
+
+    let mut bid_data: Bid;
+    if bid.data_len() == 0 {
+        // create the bid account, escrowing the bid on top of rent
+        create_pda_account(
+            bidder,
+            bid,
+            system_program,
+            BID_RESERVE,
+            program_id,
+            args.price,
+            seeds_bid,
+        )?;
+
+        bid_data = try_from_slice_unchecked(&bid.data.borrow_mut())?;
+        bid_data.bump = bump_bid;
+        bid_data.bidder = *bidder.key;
+        bid_data.space_x = args.space_x;
+        bid_data.space_y = args.space_y;
+    }
+    else {
+        bid_data = try_from_slice_unchecked(&bid.data.borrow_mut())?;
+
+        // settle the difference with the existing escrow
+        if args.price > bid_data.price {
+            invoke(
+                &system_instruction::transfer(
+                    bidder.key,
+                    bid.key,
+                    args.price - bid_data.price,
+                ),
+                &[
+                    bidder.clone(),
+                    bid.clone(),
+                    system_program.clone(),
+                ],
+            )?;
+        }
+        else if args.price < bid_data.price {
+            transfer_lamports(bid, bidder, bid_data.price - args.price)?;
+        }
+    }
+
+    // write to bid
+    bid_data.price = args.price;
+    bid_data.serialize(&mut *bid.data.borrow_mut())?;
+
+    Ok(())
+}
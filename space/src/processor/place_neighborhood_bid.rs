@@ -0,0 +1,142 @@
+use borsh::{BorshSerialize};
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    borsh::try_from_slice_unchecked,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_instruction, system_program,
+};
+
+use crate::{
+    error::CustomError,
+    instruction::PlaceNeighborhoodBidArgs,
+    processor::processor_utils::{assert_not_paused, create_pda_account, transfer_lamports},
+    state::{
+        NEIGHBORHOOD_BID_SEED,
+        NEIGHBORHOOD_BID_RESERVE,
+        NEIGHBORHOOD_METADATA_SEED,
+        Base,
+        NeighborhoodBid,
+        NeighborhoodMetadata,
+    },
+    validation_utils::{assert_keys_equal, assert_owned_by},
+};
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &PlaceNeighborhoodBidArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let base = next_account_info(account_info_iter)?;
+    let neighborhood_metadata = next_account_info(account_info_iter)?;
+    let neighborhood_bid = next_account_info(account_info_iter)?;
+    let bidder = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
+
+    if !bidder.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // check world is not paused
+    assert_owned_by(base, program_id)?;
+    let base_data: Base = try_from_slice_unchecked(&base.data.borrow())?;
+    assert_not_paused(&base_data)?;
+
+    // check programs
+    assert_keys_equal(system_program::id(), *system_program.key)?;
+
+    if args.price == 0 || args.quantity == 0 {
+        msg!("Error: bid price and quantity must be positive");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let escrow = args.price
+        .checked_mul(args.quantity)
+        .ok_or(CustomError::NumericalOverflow)?;
+
+    // deserialize and verify neighborhood metadata
+    let neighborhood_metadata_data: NeighborhoodMetadata = try_from_slice_unchecked(&neighborhood_metadata.data.borrow())?;
+    let seeds_neighborhood_metadata = &[
+        &base.key.to_bytes(),
+        NEIGHBORHOOD_METADATA_SEED,
+        &args.neighborhood_x.to_le_bytes(),
+        &args.neighborhood_y.to_le_bytes(),
+        &[neighborhood_metadata_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_neighborhood_metadata, program_id)?;
+    assert_keys_equal(key, *neighborhood_metadata.key)?;
+
+    // verify neighborhood bid account
+    let seeds_neighborhood_bid = &[
+        &base.key.to_bytes(),
+        NEIGHBORHOOD_BID_SEED,
+        &args.neighborhood_x.to_le_bytes(),
+        &args.neighborhood_y.to_le_bytes(),
+        &bidder.key.to_bytes(),
+    ];
+    let (key, bump_neighborhood_bid) = Pubkey::find_program_address(seeds_neighborhood_bid, program_id);
+    assert_keys_equal(key, *neighborhood_bid.key)?;
+    let seeds_neighborhood_bid = &[
+        &base.key.to_bytes(),
+        NEIGHBORHOOD_BID_SEED,
+        &args.neighborhood_x.to_le_bytes(),
+        &args.neighborhood_y.to_le_bytes(),
+        &bidder.key.to_bytes(),
+        &[bump_neighborhood_bid],
+    ];
+
+    let mut neighborhood_bid_data: NeighborhoodBid;
+    if neighborhood_bid.data_len() == 0 {
+        // create the neighborhood bid account, escrowing the bid on top of rent
+        create_pda_account(
+            bidder,
+            neighborhood_bid,
+            system_program,
+            NEIGHBORHOOD_BID_RESERVE,
+            program_id,
+            escrow,
+            seeds_neighborhood_bid,
+        )?;
+
+        neighborhood_bid_data = try_from_slice_unchecked(&neighborhood_bid.data.borrow_mut())?;
+        neighborhood_bid_data.bump = bump_neighborhood_bid;
+        neighborhood_bid_data.bidder = *bidder.key;
+        neighborhood_bid_data.neighborhood_x = args.neighborhood_x;
+        neighborhood_bid_data.neighborhood_y = args.neighborhood_y;
+    }
+    else {
+        neighborhood_bid_data = try_from_slice_unchecked(&neighborhood_bid.data.borrow_mut())?;
+
+        // settle the difference with the existing escrow
+        let current_escrow = neighborhood_bid_data.price
+            .checked_mul(neighborhood_bid_data.quantity)
+            .ok_or(CustomError::NumericalOverflow)?;
+        if escrow > current_escrow {
+            invoke(
+                &system_instruction::transfer(
+                    bidder.key,
+                    neighborhood_bid.key,
+                    escrow - current_escrow,
+                ),
+                &[
+                    bidder.clone(),
+                    neighborhood_bid.clone(),
+                    system_program.clone(),
+                ],
+            )?;
+        }
+        else if escrow < current_escrow {
+            transfer_lamports(neighborhood_bid, bidder, current_escrow - escrow)?;
+        }
+    }
+
+    // write to neighborhood bid
+    neighborhood_bid_data.price = args.price;
+    neighborhood_bid_data.quantity = args.quantity;
+    neighborhood_bid_data.serialize(&mut *neighborhood_bid.data.borrow_mut())?;
+
+    Ok(())
+}
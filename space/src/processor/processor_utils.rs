@@ -1,10 +1,42 @@
+use borsh::BorshSerialize;
+use metaplex_token_metadata::state::Metadata;
 use solana_program::{
+    account_info::AccountInfo,
+    borsh::try_from_slice_unchecked,
+    entrypoint::ProgramResult,
+    instruction::Instruction,
     msg,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_instruction,
+    sysvar::{Sysvar, rent::Rent},
 };
 use std::cmp;
 use crate::{
+    error::CustomError,
+    validation_utils::assert_keys_equal,
     state::{
+        BASIS_POINTS,
+        DEFAULT_MARKETPLACE_FEE_BPS,
+        DEFAULT_MAX_NEIGHBORHOOD_FEE_BPS,
+        DEFAULT_NEIGHBORHOOD_BASE_PRICE,
+        DEFAULT_NEIGHBORHOOD_FREE_RADIUS,
+        DEFAULT_NEIGHBORHOOD_RING_INCREMENT,
+        FEE_VAULT_RESERVE,
+        FEE_VAULT_SEED,
+        INITIAL_FREE_NEIGHBORHOODS,
+        MAX_BUNDLE_SPACES,
+        MAX_FREE_CREATORS,
+        MAX_NEIGHBORHOOD_COORDINATE,
         NEIGHBORHOOD_SIZE,
+        AdminAction,
+        Base,
+        FeeVault,
+        ListingMetadata,
+        NeighborhoodMetadata,
+        NeighborhoodPricing,
+        SpaceMetadata,
     },
 };
 
@@ -21,10 +53,49 @@ pub fn floor_divide(x: i64, y: usize) -> i64 {
     }
 }
 
+// reject neighborhoods outside the base world bounds or close enough to i64 extremes to overflow
+pub fn assert_in_world_bounds(base_data: &Base, neighborhood_x: i64, neighborhood_y: i64) -> ProgramResult {
+    let (min_x, min_y, max_x, max_y) = match base_data.world_bounds {
+        Some(bounds) => (bounds.min_x, bounds.min_y, bounds.max_x, bounds.max_y),
+        None => (-MAX_NEIGHBORHOOD_COORDINATE, -MAX_NEIGHBORHOOD_COORDINATE, MAX_NEIGHBORHOOD_COORDINATE, MAX_NEIGHBORHOOD_COORDINATE),
+    };
+    if neighborhood_x < min_x || neighborhood_x > max_x || neighborhood_y < min_y || neighborhood_y > max_y {
+        msg!("Error: neighborhood ({}, {}) is outside the world bounds", neighborhood_x, neighborhood_y);
+        return Err(CustomError::OutOfBounds.into());
+    }
+    Ok(())
+}
+
 pub fn get_neighborhood_xy(x: i64, y: i64) -> (i64, i64){
     return (floor_divide(x, NEIGHBORHOOD_SIZE), floor_divide(y, NEIGHBORHOOD_SIZE));
 }
 
+// spaces of a bundle rectangle in row order, which must lie within one neighborhood
+pub fn get_bundle_spaces(min_x: i64, min_y: i64, max_x: i64, max_y: i64) -> Result<Vec<(i64, i64)>, ProgramError> {
+    if min_x > max_x || min_y > max_y {
+        msg!("Error: bundle corners out of order");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    if get_neighborhood_xy(min_x, min_y) != get_neighborhood_xy(max_x, max_y) {
+        msg!("Error: bundle must lie within one neighborhood");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let width = max_x.checked_sub(min_x).and_then(|w| w.checked_add(1)).ok_or(CustomError::NumericalOverflow)?;
+    let height = max_y.checked_sub(min_y).and_then(|h| h.checked_add(1)).ok_or(CustomError::NumericalOverflow)?;
+    if width.checked_mul(height).ok_or(CustomError::NumericalOverflow)? > MAX_BUNDLE_SPACES {
+        msg!("Error: bundle cannot hold more than {} spaces", MAX_BUNDLE_SPACES);
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let mut spaces = Vec::new();
+    for y in min_y..=max_y {
+        for x in min_x..=max_x {
+            spaces.push((x, y));
+        }
+    }
+    return Ok(spaces);
+}
+
 pub fn get_space_xy_from_name(name: &str) -> (i64, i64) {
     let split = name.split('(');
     let number_str = split.last().unwrap().trim_end().trim_start();
@@ -36,10 +107,748 @@ pub fn get_space_xy_from_name(name: &str) -> (i64, i64) {
     return (x, y);
 }
 
-pub fn get_neighborhood_creation_price(n_x: i64, n_y: i64) -> u64 {
-    let dist = cmp::max(n_x.abs(), n_y.abs()) as u64;
+// pricing set by the authority, otherwise the default curve
+pub fn get_neighborhood_pricing(base_data: &Base) -> NeighborhoodPricing {
+    return base_data.neighborhood_pricing.unwrap_or(NeighborhoodPricing {
+        base_price: DEFAULT_NEIGHBORHOOD_BASE_PRICE,
+        ring_increment: DEFAULT_NEIGHBORHOOD_RING_INCREMENT,
+        free_radius: DEFAULT_NEIGHBORHOOD_FREE_RADIUS,
+        ring_cap: None,
+    });
+}
+
+pub fn get_neighborhood_creation_price(pricing: &NeighborhoodPricing, n_x: i64, n_y: i64) -> Result<u64, ProgramError> {
+    let mut ring = cmp::max(n_x.unsigned_abs(), n_y.unsigned_abs());
+    if let Some(ring_cap) = pricing.ring_cap {
+        ring = cmp::min(ring, ring_cap);
+    }
+    let priced_rings = ring.saturating_sub(pricing.free_radius);
+
+    let price = pricing.ring_increment
+        .checked_mul(priced_rings)
+        .and_then(|increment| increment.checked_add(pricing.base_price))
+        .ok_or(CustomError::NumericalOverflow)?;
+
+    return Ok(price);
+}
+
+// bound on neighborhood fee overrides, falling back to the default for bases that never set one
+pub fn get_max_neighborhood_fee_bps(base_data: &Base) -> u16 {
+    return base_data.max_neighborhood_fee_bps.unwrap_or(DEFAULT_MAX_NEIGHBORHOOD_FEE_BPS);
+}
+
+// neighborhood override if set (capped by the current base bound), otherwise the base fee
+pub fn get_marketplace_fee_bps(base_data: &Base, neighborhood_metadata_data: &NeighborhoodMetadata) -> u16 {
+    let base_fee_bps = base_data.marketplace_fee_bps.unwrap_or(DEFAULT_MARKETPLACE_FEE_BPS);
+    return match neighborhood_metadata_data.marketplace_fee_bps {
+        Some(fee_bps) => cmp::min(fee_bps, get_max_neighborhood_fee_bps(base_data)),
+        None => base_fee_bps,
+    };
+}
+
+pub fn get_marketplace_fee(price: u64, fee_bps: u16) -> Result<u64, ProgramError> {
+    if fee_bps > BASIS_POINTS {
+        msg!("Error: marketplace fee exceeds price");
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let fee = (price as u128)
+        .checked_mul(fee_bps as u128)
+        .ok_or(CustomError::NumericalOverflow)?
+        / BASIS_POINTS as u128;
+    return Ok(fee as u64);
+}
+
+// current price of a listing, interpolating dutch listings linearly between their timestamps
+pub fn get_listing_price(space_metadata_data: &SpaceMetadata, now_ts: i64) -> u64 {
+    let start_price = space_metadata_data.price;
+    let end_price = space_metadata_data.end_price;
+    let start_ts = space_metadata_data.start_timestamp;
+    let end_ts = space_metadata_data.end_timestamp;
+
+    if end_ts <= start_ts || end_price >= start_price || now_ts <= start_ts {
+        return start_price;
+    }
+    if now_ts >= end_ts {
+        return end_price;
+    }
+
+    let elapsed = (now_ts as i128 - start_ts as i128) as u128;
+    let duration = (end_ts as i128 - start_ts as i128) as u128;
+    let decline = (start_price - end_price) as u128 * elapsed / duration;
+    return start_price - decline as u64;
+}
 
-    let price = 400000+400000*cmp::max(dist-3, 0);
-    
-    return price;
-}
\ No newline at end of file
+// reset all listing terms once a space is delisted or changes hands
+pub fn clear_listing(space_metadata_data: &mut SpaceMetadata) {
+    space_metadata_data.price = 0;
+    space_metadata_data.end_price = 0;
+    space_metadata_data.start_timestamp = 0;
+    space_metadata_data.end_timestamp = 0;
+    space_metadata_data.payment_mint = Pubkey::default();
+    space_metadata_data.expires_at = 0;
+}
+
+pub fn clear_listing_metadata(listing_metadata_data: &mut ListingMetadata) {
+    listing_metadata_data.buyer = Pubkey::default();
+    listing_metadata_data.seller = Pubkey::default();
+    listing_metadata_data.token_account = Pubkey::default();
+    listing_metadata_data.vault = false;
+}
+
+// part of the marketplace fee owed to a referrer, the rest going to the neighborhood creator
+pub fn get_referral_fee(marketplace_fee: u64, base_data: &Base) -> Result<u64, ProgramError> {
+    if base_data.referral_fee_bps > BASIS_POINTS {
+        msg!("Error: referral fee exceeds marketplace fee");
+        return Err(ProgramError::InvalidAccountData);
+    }
+    return get_marketplace_fee(marketplace_fee, base_data.referral_fee_bps);
+}
+
+// buyer and seller cannot name themselves to take back a share of the fee
+pub fn assert_valid_referrer(referrer: &Option<Pubkey>, buyer: &Pubkey, seller: &Pubkey) -> ProgramResult {
+    if let Some(referrer) = referrer {
+        if referrer == buyer || referrer == seller {
+            msg!("Error: referrer cannot be the buyer or seller");
+            return Err(CustomError::InvalidReferrer.into());
+        }
+    }
+    Ok(())
+}
+
+// royalty on price owed to each verified creator of the space, in metaplex creator order
+// royalties never take more than max_royalties, the seller's proceeds after the marketplace fee
+pub fn get_royalties(space_metaplex_metadata: &AccountInfo, space_mint: &Pubkey, price: u64, max_royalties: u64) -> Result<Vec<(Pubkey, u64)>, ProgramError> {
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[
+            b"metadata",
+            metaplex_token_metadata::id().as_ref(),
+            space_mint.as_ref(),
+        ],
+        &metaplex_token_metadata::id(),
+    );
+    assert_keys_equal(metadata_key, *space_metaplex_metadata.key)?;
+    let space_metaplex_metadata_data = Metadata::from_account_info(space_metaplex_metadata)?;
+
+    let creators: Vec<(Pubkey, u8)> = space_metaplex_metadata_data.data.creators
+        .unwrap_or_default()
+        .iter()
+        .filter(|creator| creator.verified)
+        .map(|creator| (creator.address, creator.share))
+        .collect();
+    return split_royalties(price, space_metaplex_metadata_data.data.seller_fee_basis_points, &creators, max_royalties);
+}
+
+// split the royalty on price between verified creators by share, capped at max_royalties
+pub fn split_royalties(price: u64, seller_fee_bps: u16, creators: &[(Pubkey, u8)], max_royalties: u64) -> Result<Vec<(Pubkey, u64)>, ProgramError> {
+    let royalty = cmp::min(
+        get_marketplace_fee(price, cmp::min(seller_fee_bps, BASIS_POINTS))?,
+        max_royalties,
+    );
+    let mut royalties = Vec::new();
+    for (address, share) in creators {
+        if *share == 0 {
+            continue;
+        }
+        let amount = (royalty as u128)
+            .checked_mul(*share as u128)
+            .ok_or(CustomError::NumericalOverflow)?
+            / 100;
+        royalties.push((*address, amount as u64));
+    }
+    return Ok(royalties);
+}
+
+// pay from alice in SOL, or from alice's payment ATA to an ATA when priced in a token
+pub fn transfer_payment<'a>(
+    alice: &AccountInfo<'a>,
+    alice_ata_payment: Option<&AccountInfo<'a>>,
+    to: &AccountInfo<'a>,
+    amount: u64,
+    system_program: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+) -> ProgramResult {
+    if amount == 0 {
+        return Ok(());
+    }
+    match alice_ata_payment {
+        None => invoke(
+            &system_instruction::transfer(
+                alice.key,
+                to.key,
+                amount,
+            ),
+            &[
+                alice.clone(),
+                to.clone(),
+                system_program.clone(),
+            ],
+        ),
+        Some(alice_ata_payment) => invoke(
+            &spl_token::instruction::transfer(
+                token_program.key,
+                alice_ata_payment.key,
+                to.key,
+                alice.key,
+                &[],
+                amount,
+            )?,
+            &[
+                token_program.clone(),
+                alice_ata_payment.clone(),
+                to.clone(),
+                alice.clone(),
+            ],
+        ),
+    }
+}
+
+// verify the neighborhood fee vault, creating it on first use with rent from payer
+pub fn create_fee_vault_if_needed<'a>(
+    program_id: &Pubkey,
+    base: &AccountInfo<'a>,
+    fee_vault: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    neighborhood_x: i64,
+    neighborhood_y: i64,
+) -> ProgramResult {
+    let seeds_fee_vault = &[
+        &base.key.to_bytes(),
+        FEE_VAULT_SEED,
+        &neighborhood_x.to_le_bytes(),
+        &neighborhood_y.to_le_bytes(),
+    ];
+    let (key, bump_fee_vault) = Pubkey::find_program_address(seeds_fee_vault, program_id);
+    assert_keys_equal(key, *fee_vault.key)?;
+    if fee_vault.data_len() != 0 {
+        return Ok(());
+    }
+    let seeds_fee_vault = &[
+        &base.key.to_bytes(),
+        FEE_VAULT_SEED,
+        &neighborhood_x.to_le_bytes(),
+        &neighborhood_y.to_le_bytes(),
+        &[bump_fee_vault],
+    ];
+
+    create_pda_account(
+        payer,
+        fee_vault,
+        system_program,
+        FEE_VAULT_RESERVE,
+        program_id,
+        0,
+        seeds_fee_vault,
+    )?;
+
+    let mut fee_vault_data: FeeVault = try_from_slice_unchecked(&fee_vault.data.borrow())?;
+    fee_vault_data.bump = bump_fee_vault;
+    fee_vault_data.neighborhood_x = neighborhood_x;
+    fee_vault_data.neighborhood_y = neighborhood_y;
+    fee_vault_data.serialize(&mut *fee_vault.data.borrow_mut())?;
+    Ok(())
+}
+
+// add a fee already paid into the fee vault to its lifetime total
+pub fn record_fee(fee_vault: &AccountInfo, amount: u64) -> ProgramResult {
+    let mut fee_vault_data: FeeVault = try_from_slice_unchecked(&fee_vault.data.borrow())?;
+    fee_vault_data.lifetime_fees = fee_vault_data.lifetime_fees
+        .checked_add(amount)
+        .ok_or(CustomError::NumericalOverflow)?;
+    fee_vault_data.serialize(&mut *fee_vault.data.borrow_mut())?;
+    Ok(())
+}
+
+// trading instructions refuse to run while the base authority has paused the world
+pub fn assert_not_paused(base_data: &Base) -> ProgramResult {
+    if base_data.paused {
+        msg!("Error: paused by base authority");
+        return Err(CustomError::Paused.into());
+    }
+    Ok(())
+}
+
+// once the authority sets an admin delay, admin actions must go through the queue
+pub fn assert_no_admin_delay(base_data: &Base) -> ProgramResult {
+    if base_data.admin_delay > 0 {
+        msg!("Error: admin delay is set, queue this action with QueueAdminAction");
+        return Err(CustomError::Timelocked.into());
+    }
+    Ok(())
+}
+
+// convert authority_privileges from bases initialized before free_neighborhoods existed
+pub fn migrate_authority_privileges(base_data: &mut Base) {
+    if base_data.authority_privileges && base_data.free_neighborhoods == 0 && base_data.free_creators.is_empty() {
+        base_data.free_neighborhoods = INITIAL_FREE_NEIGHBORHOODS;
+    }
+    base_data.authority_privileges = false;
+}
+
+// validate a queued admin action and write it to base
+pub fn apply_admin_action(base_data: &mut Base, action: &AdminAction) -> ProgramResult {
+    match *action {
+        AdminAction::ProposeAuthority { new_authority } => {
+            base_data.pending_authority = Some(new_authority);
+        }
+        AdminAction::RevokeAuthorityPrivileges => {
+            if base_data.free_neighborhoods == 0 && base_data.free_creators.is_empty() {
+                msg!("Price exempt status already revoked!");
+                return Err(ProgramError::InvalidAccountData);
+            }
+            base_data.authority_privileges = false;
+            base_data.free_neighborhoods = 0;
+            base_data.free_creators = Vec::new();
+        }
+        AdminAction::SetMarketplaceFee { marketplace_fee_bps, max_neighborhood_fee_bps } => {
+            if marketplace_fee_bps > BASIS_POINTS || max_neighborhood_fee_bps > BASIS_POINTS {
+                msg!("Error: fee cannot exceed {} basis points", BASIS_POINTS);
+                return Err(ProgramError::InvalidInstructionData);
+            }
+            base_data.marketplace_fee_bps = Some(marketplace_fee_bps);
+            base_data.max_neighborhood_fee_bps = Some(max_neighborhood_fee_bps);
+        }
+        AdminAction::SetReferralFee { referral_fee_bps } => {
+            if referral_fee_bps > BASIS_POINTS {
+                msg!("Error: referral fee cannot exceed {} basis points", BASIS_POINTS);
+                return Err(ProgramError::InvalidInstructionData);
+            }
+            base_data.referral_fee_bps = referral_fee_bps;
+        }
+        AdminAction::SetAdminDelay { admin_delay } => {
+            if admin_delay < 0 {
+                msg!("Error: admin delay cannot be negative");
+                return Err(ProgramError::InvalidInstructionData);
+            }
+            base_data.admin_delay = admin_delay;
+        }
+        AdminAction::SetFreeNeighborhoods { free_neighborhoods, ref free_creators } => {
+            if free_creators.len() > MAX_FREE_CREATORS {
+                msg!("Error: at most {} free creators", MAX_FREE_CREATORS);
+                return Err(ProgramError::InvalidInstructionData);
+            }
+            base_data.authority_privileges = false;
+            base_data.free_neighborhoods = free_neighborhoods;
+            base_data.free_creators = free_creators.clone();
+        }
+        AdminAction::SetNeighborhoodPricing { pricing } => {
+            base_data.neighborhood_pricing = Some(pricing);
+        }
+        AdminAction::SetRequireAdjacent { require_adjacent } => {
+            base_data.require_adjacent = require_adjacent;
+        }
+        AdminAction::SetWorldBounds { world_bounds } => {
+            if let Some(bounds) = world_bounds {
+                let in_range = |c: i64| (-MAX_NEIGHBORHOOD_COORDINATE..=MAX_NEIGHBORHOOD_COORDINATE).contains(&c);
+                if !(in_range(bounds.min_x) && in_range(bounds.min_y) && in_range(bounds.max_x) && in_range(bounds.max_y)) {
+                    msg!("Error: world bounds must be within {} of the origin", MAX_NEIGHBORHOOD_COORDINATE);
+                    return Err(ProgramError::InvalidInstructionData);
+                }
+                if bounds.min_x > bounds.max_x || bounds.min_y > bounds.max_y {
+                    msg!("Error: world bounds are empty");
+                    return Err(ProgramError::InvalidInstructionData);
+                }
+            }
+            base_data.world_bounds = world_bounds;
+        }
+    }
+    Ok(())
+}
+
+// create_account fails once anyone has sent lamports to the address, so top up, allocate and assign instead
+pub fn get_create_pda_instructions(
+    payer: &Pubkey,
+    account: &Pubkey,
+    current_lamports: u64,
+    required_lamports: u64,
+    space: usize,
+    owner: &Pubkey,
+) -> Vec<Instruction> {
+    if current_lamports == 0 {
+        return vec![system_instruction::create_account(payer, account, required_lamports, space as u64, owner)];
+    }
+    let mut instructions = Vec::new();
+    let shortfall = required_lamports.saturating_sub(current_lamports);
+    if shortfall > 0 {
+        instructions.push(system_instruction::transfer(payer, account, shortfall));
+    }
+    instructions.push(system_instruction::allocate(account, space as u64));
+    instructions.push(system_instruction::assign(account, owner));
+    return instructions;
+}
+
+// create a PDA owned by owner, funded by payer with rent plus extra_lamports
+pub fn create_pda_account<'a>(
+    payer: &AccountInfo<'a>,
+    account: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    space: usize,
+    owner: &Pubkey,
+    extra_lamports: u64,
+    seeds: &[&[u8]],
+) -> ProgramResult {
+    let required_lamports = Rent::get()?
+        .minimum_balance(space)
+        .max(1)
+        .checked_add(extra_lamports)
+        .ok_or(CustomError::NumericalOverflow)?;
+    let instructions = get_create_pda_instructions(payer.key, account.key, account.lamports(), required_lamports, space, owner);
+    for instruction in instructions.iter() {
+        invoke_signed(
+            instruction,
+            &[
+                payer.clone(),
+                account.clone(),
+                system_program.clone(),
+            ],
+            &[seeds],
+        )?;
+    }
+    Ok(())
+}
+
+// move lamports out of an account owned by this program
+pub fn transfer_lamports(from: &AccountInfo, to: &AccountInfo, amount: u64) -> ProgramResult {
+    let from_lamports = from.lamports()
+        .checked_sub(amount)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    let to_lamports = to.lamports()
+        .checked_add(amount)
+        .ok_or(CustomError::NumericalOverflow)?;
+    **from.try_borrow_mut_lamports()? = from_lamports;
+    **to.try_borrow_mut_lamports()? = to_lamports;
+    Ok(())
+}
+
+// drain an account owned by this program into destination and wipe its data
+pub fn close_account(account: &AccountInfo, destination: &AccountInfo) -> ProgramResult {
+    transfer_lamports(account, destination, account.lamports())?;
+    let mut data = account.data.borrow_mut();
+    for val in data.iter_mut() {
+        *val = 0;
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_ata_if_needed<'a>(
+    payer: &AccountInfo<'a>,
+    wallet: &AccountInfo<'a>,
+    mint: &AccountInfo<'a>,
+    ata: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    rent_program: &AccountInfo<'a>,
+    associated_token_program: &AccountInfo<'a>,
+) -> ProgramResult {
+    if ata.data_len() == 0 {
+        invoke(
+            &spl_associated_token_account::create_associated_token_account(
+                payer.key,
+                wallet.key,
+                mint.key,
+            ),
+            &[
+                payer.clone(),
+                ata.clone(),
+                wallet.clone(),
+                mint.clone(),
+                system_program.clone(),
+                token_program.clone(),
+                rent_program.clone(),
+                associated_token_program.clone(),
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn default_pricing() -> NeighborhoodPricing {
+        return NeighborhoodPricing {
+            base_price: DEFAULT_NEIGHBORHOOD_BASE_PRICE,
+            ring_increment: DEFAULT_NEIGHBORHOOD_RING_INCREMENT,
+            free_radius: DEFAULT_NEIGHBORHOOD_FREE_RADIUS,
+            ring_cap: None,
+        };
+    }
+
+    // a base as init_base leaves it before any admin action
+    fn new_base() -> Base {
+        return try_from_slice_unchecked(&[0u8; crate::state::BASE_RESERVE]).unwrap();
+    }
+
+    fn dutch_listing(price: u64, end_price: u64, start_timestamp: i64, end_timestamp: i64) -> SpaceMetadata {
+        return SpaceMetadata {
+            bump: 0,
+            mint: Pubkey::default(),
+            price,
+            space_x: 0,
+            space_y: 0,
+            end_price,
+            start_timestamp,
+            end_timestamp,
+            payment_mint: Pubkey::default(),
+            expires_at: 0,
+        };
+    }
+
+    #[test]
+    fn pre_funded_fee_vault_is_allocated_not_created() {
+        let program_id = Pubkey::new_unique();
+        let base = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let (fee_vault, _) = Pubkey::find_program_address(
+            &[&base.to_bytes(), FEE_VAULT_SEED, &0i64.to_le_bytes(), &0i64.to_le_bytes()],
+            &program_id,
+        );
+        let required = Rent::default().minimum_balance(FEE_VAULT_RESERVE);
+
+        // untouched address is created in one instruction
+        let instructions = get_create_pda_instructions(&payer, &fee_vault, 0, required, FEE_VAULT_RESERVE, &program_id);
+        assert_eq!(instructions, vec![
+            system_instruction::create_account(&payer, &fee_vault, required, FEE_VAULT_RESERVE as u64, &program_id),
+        ]);
+
+        // a 1 lamport donation only changes how much the payer tops up
+        let instructions = get_create_pda_instructions(&payer, &fee_vault, 1, required, FEE_VAULT_RESERVE, &program_id);
+        assert_eq!(instructions, vec![
+            system_instruction::transfer(&payer, &fee_vault, required - 1),
+            system_instruction::allocate(&fee_vault, FEE_VAULT_RESERVE as u64),
+            system_instruction::assign(&fee_vault, &program_id),
+        ]);
+
+        // a donation covering rent needs no top up at all
+        let instructions = get_create_pda_instructions(&payer, &fee_vault, required + 5, required, FEE_VAULT_RESERVE, &program_id);
+        assert_eq!(instructions, vec![
+            system_instruction::allocate(&fee_vault, FEE_VAULT_RESERVE as u64),
+            system_instruction::assign(&fee_vault, &program_id),
+        ]);
+    }
+
+    #[test]
+    fn default_curve_prices_every_ring() {
+        let pricing = default_pricing();
+        for ring in 0..=50i64 {
+            let expected = 400000 + 400000 * cmp::max(ring - 3, 0) as u64;
+            // every neighborhood on the ring, walking its four edges
+            for i in -ring..=ring {
+                for (n_x, n_y) in [(ring, i), (-ring, i), (i, ring), (i, -ring)] {
+                    assert_eq!(get_neighborhood_creation_price(&pricing, n_x, n_y).unwrap(), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn inner_rings_cost_base_price() {
+        let pricing = default_pricing();
+        assert_eq!(get_neighborhood_creation_price(&pricing, 0, 0).unwrap(), 400000);
+        assert_eq!(get_neighborhood_creation_price(&pricing, 1, -2).unwrap(), 400000);
+        assert_eq!(get_neighborhood_creation_price(&pricing, -3, 3).unwrap(), 400000);
+        assert_eq!(get_neighborhood_creation_price(&pricing, 4, 0).unwrap(), 800000);
+    }
+
+    #[test]
+    fn ring_cap_flattens_curve() {
+        let pricing = NeighborhoodPricing { ring_cap: Some(5), ..default_pricing() };
+        for ring in 0..=20i64 {
+            let expected = 400000 + 400000 * (cmp::min(ring, 5) - cmp::min(ring, 3)) as u64;
+            assert_eq!(get_neighborhood_creation_price(&pricing, ring, -ring).unwrap(), expected);
+        }
+
+        // a cap inside the free radius leaves every ring at the base price
+        let pricing = NeighborhoodPricing { ring_cap: Some(1), ..default_pricing() };
+        assert_eq!(get_neighborhood_creation_price(&pricing, 100, 0).unwrap(), 400000);
+    }
+
+    #[test]
+    fn extreme_coordinates_do_not_panic() {
+        let pricing = default_pricing();
+        assert!(get_neighborhood_creation_price(&pricing, i64::MIN, 0).is_err());
+        assert!(get_neighborhood_creation_price(&pricing, i64::MIN, i64::MAX).is_err());
+
+        let pricing = NeighborhoodPricing { ring_increment: 1, base_price: 0, free_radius: 0, ring_cap: None };
+        assert_eq!(get_neighborhood_creation_price(&pricing, i64::MIN, 0).unwrap(), 1u64 << 63);
+
+        let pricing = NeighborhoodPricing { ring_increment: 1, base_price: u64::MAX, free_radius: 0, ring_cap: None };
+        assert!(get_neighborhood_creation_price(&pricing, 1, 0).is_err());
+        assert_eq!(get_neighborhood_creation_price(&pricing, 0, 0).unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn royalties_split_by_share() {
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+        let c = Pubkey::new_unique();
+        let royalties = split_royalties(1000000, 500, &[(a, 60), (b, 40), (c, 0)], u64::MAX).unwrap();
+        assert_eq!(royalties, vec![(a, 30000), (b, 20000)]);
+    }
+
+    #[test]
+    fn royalties_capped_at_seller_proceeds() {
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+        let price = 1000000;
+        let seller_proceeds = price - get_marketplace_fee(price, 250).unwrap();
+
+        // full royalty plus the marketplace fee would exceed the price
+        let royalties = split_royalties(price, 10000, &[(a, 50), (b, 50)], seller_proceeds).unwrap();
+        let total: u64 = royalties.iter().map(|(_, amount)| amount).sum();
+        assert!(total <= seller_proceeds);
+        assert_eq!(royalties, vec![(a, seller_proceeds / 2), (b, seller_proceeds / 2)]);
+
+        // metadata claiming more than 100% is treated as 100%
+        assert!(split_royalties(price, u16::MAX, &[(a, 100)], seller_proceeds).is_ok());
+        assert_eq!(split_royalties(price, 500, &[(a, 100)], 0).unwrap(), vec![(a, 0)]);
+    }
+
+    #[test]
+    fn legacy_authority_privileges_become_free_neighborhoods() {
+        // a base written before free_neighborhoods existed, with only authority_privileges set
+        let mut data = vec![0u8; crate::state::BASE_RESERVE];
+        data[8 + 32] = 1;
+        let mut base_data: Base = try_from_slice_unchecked(&data).unwrap();
+        assert!(base_data.authority_privileges);
+        migrate_authority_privileges(&mut base_data);
+        assert!(!base_data.authority_privileges);
+        assert_eq!(base_data.free_neighborhoods, INITIAL_FREE_NEIGHBORHOODS);
+
+        // once used up, the quota is not granted again
+        base_data.free_neighborhoods = 0;
+        migrate_authority_privileges(&mut base_data);
+        assert_eq!(base_data.free_neighborhoods, 0);
+
+        // a revoked legacy base gets nothing
+        data[8 + 32] = 0;
+        let mut base_data: Base = try_from_slice_unchecked(&data).unwrap();
+        migrate_authority_privileges(&mut base_data);
+        assert_eq!(base_data.free_neighborhoods, 0);
+    }
+
+    #[test]
+    fn admin_action_reserve_fits_largest_action() {
+        let queued_action = crate::state::QueuedAdminAction {
+            bump: 255,
+            id: u64::MAX,
+            eta: i64::MAX,
+            action: AdminAction::SetFreeNeighborhoods {
+                free_neighborhoods: u64::MAX,
+                free_creators: vec![Pubkey::new_unique(); MAX_FREE_CREATORS],
+            },
+            queued_at: i64::MAX,
+        };
+        assert!(queued_action.try_to_vec().unwrap().len() <= crate::state::ADMIN_ACTION_RESERVE);
+    }
+
+    #[test]
+    fn dutch_listing_declines_linearly() {
+        let listing = dutch_listing(1000, 200, 100, 200);
+        assert_eq!(get_listing_price(&listing, 0), 1000);
+        assert_eq!(get_listing_price(&listing, 100), 1000);
+        assert_eq!(get_listing_price(&listing, 150), 600);
+        assert_eq!(get_listing_price(&listing, 200), 200);
+        assert_eq!(get_listing_price(&listing, i64::MAX), 200);
+
+        // fixed price and malformed listings stay at the start price
+        assert_eq!(get_listing_price(&dutch_listing(1000, 0, 0, 0), 150), 1000);
+        assert_eq!(get_listing_price(&dutch_listing(1000, 200, 200, 100), 150), 1000);
+        assert_eq!(get_listing_price(&dutch_listing(1000, 2000, 100, 200), 150), 1000);
+
+        // extreme timestamps do not overflow
+        let listing = dutch_listing(u64::MAX, 0, i64::MIN, i64::MAX);
+        assert_eq!(get_listing_price(&listing, 0), u64::MAX / 2);
+    }
+
+    #[test]
+    fn dutch_listing_can_decay_to_zero() {
+        // change_offer rejects a zero end price, but older listings may still have one
+        let listing = dutch_listing(1000, 0, 100, 200);
+        assert_eq!(get_listing_price(&listing, 199), 10);
+        assert_eq!(get_listing_price(&listing, 200), 0);
+        assert_eq!(get_listing_price(&listing, i64::MAX), 0);
+
+        // a free listing pays no fee, so nothing is transferred and only the buyer's signature guards the sale
+        assert_eq!(get_marketplace_fee(0, 200).unwrap(), 0);
+    }
+
+    #[test]
+    fn bundle_spaces_reject_bad_rectangles() {
+        assert_eq!(get_bundle_spaces(0, 0, 1, 1).unwrap(), vec![(0, 0), (1, 0), (0, 1), (1, 1)]);
+        assert_eq!(get_bundle_spaces(-4, -4, -1, -1).unwrap().len(), MAX_BUNDLE_SPACES as usize);
+
+        // inverted corners
+        assert!(get_bundle_spaces(1, 0, 0, 0).is_err());
+        assert!(get_bundle_spaces(0, 1, 0, 0).is_err());
+
+        // more than MAX_BUNDLE_SPACES
+        assert!(get_bundle_spaces(0, 0, 16, 0).is_err());
+        assert!(get_bundle_spaces(0, 0, 4, 3).is_err());
+
+        // across a neighborhood edge
+        assert!(get_bundle_spaces(-1, 0, 0, 0).is_err());
+
+        // rectangles whose size overflows
+        assert!(get_bundle_spaces(i64::MIN, 0, i64::MAX, 0).is_err());
+    }
+
+    #[test]
+    fn marketplace_fee_at_bounds() {
+        assert_eq!(get_marketplace_fee(u64::MAX, 0).unwrap(), 0);
+        assert_eq!(get_marketplace_fee(u64::MAX, BASIS_POINTS).unwrap(), u64::MAX);
+        assert_eq!(get_marketplace_fee(12345, 100).unwrap(), 123);
+        assert!(get_marketplace_fee(1, BASIS_POINTS + 1).is_err());
+    }
+
+    #[test]
+    fn neighborhood_fee_capped_by_base_bound() {
+        let mut base_data = new_base();
+        let mut neighborhood_metadata_data: NeighborhoodMetadata =
+            try_from_slice_unchecked(&[0u8; crate::state::NEIGHBORHOOD_METADATA_RESERVE]).unwrap();
+
+        // bases that never set fees use the defaults
+        assert_eq!(get_marketplace_fee_bps(&base_data, &neighborhood_metadata_data), DEFAULT_MARKETPLACE_FEE_BPS);
+        neighborhood_metadata_data.marketplace_fee_bps = Some(BASIS_POINTS);
+        assert_eq!(get_marketplace_fee_bps(&base_data, &neighborhood_metadata_data), DEFAULT_MAX_NEIGHBORHOOD_FEE_BPS);
+
+        apply_admin_action(&mut base_data, &AdminAction::SetMarketplaceFee {
+            marketplace_fee_bps: 0,
+            max_neighborhood_fee_bps: 300,
+        }).unwrap();
+        assert_eq!(get_marketplace_fee_bps(&base_data, &neighborhood_metadata_data), 300);
+        neighborhood_metadata_data.marketplace_fee_bps = Some(0);
+        assert_eq!(get_marketplace_fee_bps(&base_data, &neighborhood_metadata_data), 0);
+        neighborhood_metadata_data.marketplace_fee_bps = None;
+        assert_eq!(get_marketplace_fee_bps(&base_data, &neighborhood_metadata_data), 0);
+    }
+
+    #[test]
+    fn world_bounds_at_max_coordinate() {
+        let mut base_data = new_base();
+
+        // unbounded worlds stop at MAX_NEIGHBORHOOD_COORDINATE
+        assert!(assert_in_world_bounds(&base_data, MAX_NEIGHBORHOOD_COORDINATE, -MAX_NEIGHBORHOOD_COORDINATE).is_ok());
+        assert!(assert_in_world_bounds(&base_data, MAX_NEIGHBORHOOD_COORDINATE + 1, 0).is_err());
+        assert!(assert_in_world_bounds(&base_data, 0, -MAX_NEIGHBORHOOD_COORDINATE - 1).is_err());
+
+        // bounds may reach but not pass MAX_NEIGHBORHOOD_COORDINATE
+        let bounds = |min: i64, max: i64| AdminAction::SetWorldBounds {
+            world_bounds: Some(crate::state::WorldBounds { min_x: min, min_y: min, max_x: max, max_y: max }),
+        };
+        assert!(apply_admin_action(&mut base_data, &bounds(-MAX_NEIGHBORHOOD_COORDINATE, MAX_NEIGHBORHOOD_COORDINATE)).is_ok());
+        assert!(apply_admin_action(&mut base_data, &bounds(-MAX_NEIGHBORHOOD_COORDINATE - 1, 0)).is_err());
+        assert!(apply_admin_action(&mut base_data, &bounds(0, MAX_NEIGHBORHOOD_COORDINATE + 1)).is_err());
+        assert!(apply_admin_action(&mut base_data, &bounds(1, 0)).is_err());
+
+        // a rejected action leaves the previous bounds in place
+        assert_eq!(base_data.world_bounds.unwrap().max_x, MAX_NEIGHBORHOOD_COORDINATE);
+
+        assert!(apply_admin_action(&mut base_data, &bounds(-2, 2)).is_ok());
+        assert!(assert_in_world_bounds(&base_data, 2, -2).is_ok());
+        assert!(assert_in_world_bounds(&base_data, 3, 0).is_err());
+        assert!(assert_in_world_bounds(&base_data, 0, -3).is_err());
+    }
+}
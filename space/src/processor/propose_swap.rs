@@ -0,0 +1,250 @@
+use borsh::{BorshSerialize};
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    borsh::try_from_slice_unchecked,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    system_program,
+    sysvar::rent,
+};
+use spl_token;
+
+use crate::{
+    error::CustomError,
+    instruction::ProposeSwapArgs,
+    processor::processor_utils::{assert_not_paused, clear_listing, clear_listing_metadata, create_pda_account},
+    state::{
+        LISTING_METADATA_SEED,
+        SPACE_METADATA_SEED,
+        SWAP_SEED,
+        SWAP_VAULT_SEED,
+        SWAP_RESERVE,
+        Base,
+        ListingMetadata,
+        SpaceMetadata,
+        Swap,
+    },
+    validation_utils::{assert_is_ata, assert_keys_equal, assert_owned_by},
+};
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &ProposeSwapArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let base = next_account_info(account_info_iter)?;
+    let offered_space_metadata = next_account_info(account_info_iter)?;
+    let wanted_space_metadata = next_account_info(account_info_iter)?;
+    let offered_mint = next_account_info(account_info_iter)?;
+    let swap = next_account_info(account_info_iter)?;
+    let swap_vault = next_account_info(account_info_iter)?;
+    let proposer = next_account_info(account_info_iter)?;
+    let proposer_ata_offered = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
+    let token_program = next_account_info(account_info_iter)?;
+    let rent_sysvar_info = next_account_info(account_info_iter)?;
+    let offered_listing_metadata = next_account_info(account_info_iter)?;
+
+    if !proposer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // check world is not paused
+    assert_owned_by(base, program_id)?;
+    let base_data: Base = try_from_slice_unchecked(&base.data.borrow())?;
+    assert_not_paused(&base_data)?;
+
+    // check programs
+    assert_keys_equal(system_program::id(), *system_program.key)?;
+    assert_keys_equal(spl_token::id(), *token_program.key)?;
+    assert_keys_equal(rent::id(), *rent_sysvar_info.key)?;
+
+    if args.offered_x == args.wanted_x && args.offered_y == args.wanted_y {
+        msg!("Error: cannot swap a space for itself");
+        return Err(CustomError::NotSwappable.into());
+    }
+
+    // deserialize and verify offered space metadata
+    let mut offered_space_metadata_data: SpaceMetadata = try_from_slice_unchecked(&offered_space_metadata.data.borrow())?;
+
+    if offered_space_metadata_data.mint != *offered_mint.key {
+        msg!("Error: space account does not match mint account");
+        return Err(CustomError::MintMismatch.into());
+    }
+
+    let seeds_offered_space_metadata = &[
+        &base.key.to_bytes(),
+        SPACE_METADATA_SEED,
+        &args.offered_x.to_le_bytes(),
+        &args.offered_y.to_le_bytes(),
+        &[offered_space_metadata_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_offered_space_metadata, program_id)?;
+    assert_keys_equal(key, *offered_space_metadata.key)?;
+
+    // verify offered space listing metadata
+    let seeds_offered_listing_metadata = &[
+        &base.key.to_bytes(),
+        LISTING_METADATA_SEED,
+        &args.offered_x.to_le_bytes(),
+        &args.offered_y.to_le_bytes(),
+    ];
+    let (key, _) = Pubkey::find_program_address(seeds_offered_listing_metadata, program_id);
+    assert_keys_equal(key, *offered_listing_metadata.key)?;
+
+    // deserialize and verify wanted space metadata
+    let wanted_space_metadata_data: SpaceMetadata = try_from_slice_unchecked(&wanted_space_metadata.data.borrow())?;
+    let seeds_wanted_space_metadata = &[
+        &base.key.to_bytes(),
+        SPACE_METADATA_SEED,
+        &args.wanted_x.to_le_bytes(),
+        &args.wanted_y.to_le_bytes(),
+        &[wanted_space_metadata_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_wanted_space_metadata, program_id)?;
+    assert_keys_equal(key, *wanted_space_metadata.key)?;
+
+    // check ATAs
+    assert_is_ata(proposer_ata_offered, proposer.key, offered_mint.key)?;
+
+    // check NFT owned
+    let proposer_ata_offered_data = spl_token::state::Account::unpack(&proposer_ata_offered.data.borrow())?;
+    if proposer_ata_offered_data.amount != 1 {
+        msg!("Error: token account does not own token");
+        return Err(CustomError::MissingTokenOwner.into());
+    }
+
+    // verify swap account
+    let seeds_swap = &[
+        &base.key.to_bytes(),
+        SWAP_SEED,
+        &args.offered_x.to_le_bytes(),
+        &args.offered_y.to_le_bytes(),
+        &args.wanted_x.to_le_bytes(),
+        &args.wanted_y.to_le_bytes(),
+    ];
+    let (key, bump_swap) = Pubkey::find_program_address(seeds_swap, program_id);
+    assert_keys_equal(key, *swap.key)?;
+    let seeds_swap = &[
+        &base.key.to_bytes(),
+        SWAP_SEED,
+        &args.offered_x.to_le_bytes(),
+        &args.offered_y.to_le_bytes(),
+        &args.wanted_x.to_le_bytes(),
+        &args.wanted_y.to_le_bytes(),
+        &[bump_swap],
+    ];
+
+    // verify swap vault
+    let seeds_swap_vault = &[
+        &base.key.to_bytes(),
+        SWAP_VAULT_SEED,
+        &args.offered_x.to_le_bytes(),
+        &args.offered_y.to_le_bytes(),
+        &args.wanted_x.to_le_bytes(),
+        &args.wanted_y.to_le_bytes(),
+    ];
+    let (key, bump_swap_vault) = Pubkey::find_program_address(seeds_swap_vault, program_id);
+    assert_keys_equal(key, *swap_vault.key)?;
+    let seeds_swap_vault = &[
+        &base.key.to_bytes(),
+        SWAP_VAULT_SEED,
+        &args.offered_x.to_le_bytes(),
+        &args.offered_y.to_le_bytes(),
+        &args.wanted_x.to_le_bytes(),
+        &args.wanted_y.to_le_bytes(),
+        &[bump_swap_vault],
+    ];
+
+    // check swap is not already proposed
+    if swap.data_len() != 0 {
+        msg!("Error: swap already proposed");
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    // create the swap account, escrowing the proposer top up on top of rent
+    create_pda_account(
+        proposer,
+        swap,
+        system_program,
+        SWAP_RESERVE,
+        program_id,
+        args.proposer_top_up,
+        seeds_swap,
+    )?;
+
+    // create the swap vault, owned by the swap account
+    create_pda_account(
+        proposer,
+        swap_vault,
+        system_program,
+        spl_token::state::Account::LEN,
+        token_program.key,
+        0,
+        seeds_swap_vault,
+    )?;
+    invoke(
+        &spl_token::instruction::initialize_account(
+            token_program.key,
+            swap_vault.key,
+            offered_mint.key,
+            swap.key,
+        )?,
+        &[
+            token_program.clone(),
+            swap_vault.clone(),
+            offered_mint.clone(),
+            swap.clone(),
+            rent_sysvar_info.clone(),
+        ],
+    )?;
+
+    // transfer NFT from proposer to swap vault
+    invoke(
+        &spl_token::instruction::transfer(
+            token_program.key,
+            proposer_ata_offered.key,
+            swap_vault.key,
+            proposer.key,
+            &[],
+            1,
+        )?,
+        &[
+            token_program.clone(),
+            proposer_ata_offered.clone(),
+            swap_vault.clone(),
+            proposer.clone(),
+        ],
+    )?;
+
+    // the space left the proposer, so any sell offer is void
+    clear_listing(&mut offered_space_metadata_data);
+    offered_space_metadata_data.serialize(&mut *offered_space_metadata.data.borrow_mut())?;
+    if offered_listing_metadata.data_len() != 0 {
+        let mut offered_listing_metadata_data: ListingMetadata = try_from_slice_unchecked(&offered_listing_metadata.data.borrow())?;
+        clear_listing_metadata(&mut offered_listing_metadata_data);
+        offered_listing_metadata_data.serialize(&mut *offered_listing_metadata.data.borrow_mut())?;
+    }
+
+    // write to swap
+    let mut swap_data: Swap = try_from_slice_unchecked(&swap.data.borrow_mut())?;
+    swap_data.bump = bump_swap;
+    swap_data.vault_bump = bump_swap_vault;
+    swap_data.proposer = *proposer.key;
+    swap_data.offered_mint = *offered_mint.key;
+    swap_data.offered_x = args.offered_x;
+    swap_data.offered_y = args.offered_y;
+    swap_data.wanted_mint = wanted_space_metadata_data.mint;
+    swap_data.wanted_x = args.wanted_x;
+    swap_data.wanted_y = args.wanted_y;
+    swap_data.proposer_top_up = args.proposer_top_up;
+    swap_data.acceptor_top_up = args.acceptor_top_up;
+    swap_data.serialize(&mut *swap.data.borrow_mut())?;
+
+    Ok(())
+}
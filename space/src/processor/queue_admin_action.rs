@@ -0,0 +1,109 @@
+use borsh::{BorshSerialize};
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    borsh::try_from_slice_unchecked,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+    sysvar::Sysvar,
+};
+
+use crate::{
+    error::CustomError,
+    instruction::QueueAdminActionArgs,
+    processor::processor_utils::{apply_admin_action, create_pda_account},
+    state::{
+        ADMIN_ACTION_RESERVE,
+        ADMIN_ACTION_SEED,
+        Base,
+        QueuedAdminAction,
+    },
+    validation_utils::{assert_keys_equal, assert_owned_by},
+};
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &QueueAdminActionArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let base = next_account_info(account_info_iter)?;
+    let authority = next_account_info(account_info_iter)?;
+    let queued_action = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
+
+    // check programs
+    assert_keys_equal(system_program::id(), *system_program.key)?;
+
+    assert_owned_by(base, program_id)?;
+    let mut base_data: Base = try_from_slice_unchecked(&base.data.borrow_mut())?;
+
+    if !authority.is_signer {
+        msg!("Error: Missing signer");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // check that signer is authority of base
+    assert_keys_equal(base_data.authority, *authority.key)?;
+
+    // reject actions that would fail when executed
+    apply_admin_action(&mut base_data.clone(), &args.action)?;
+
+    // verify queued action account
+    let id = base_data.admin_action_count;
+    let seeds_queued_action = &[
+        &base.key.to_bytes(),
+        ADMIN_ACTION_SEED,
+        &id.to_le_bytes(),
+    ];
+    let (key, bump_queued_action) = Pubkey::find_program_address(seeds_queued_action, program_id);
+    assert_keys_equal(key, *queued_action.key)?;
+    let seeds_queued_action = &[
+        &base.key.to_bytes(),
+        ADMIN_ACTION_SEED,
+        &id.to_le_bytes(),
+        &[bump_queued_action],
+    ];
+
+    if queued_action.data_len() != 0 {
+        msg!("Error: admin action already queued");
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    let now_ts = Clock::get()?.unix_timestamp;
+    let eta = now_ts
+        .checked_add(base_data.admin_delay)
+        .ok_or(CustomError::NumericalOverflow)?;
+
+    create_pda_account(
+        authority,
+        queued_action,
+        system_program,
+        ADMIN_ACTION_RESERVE,
+        program_id,
+        0,
+        seeds_queued_action,
+    )?;
+
+    // write to queued action
+    let queued_action_data = QueuedAdminAction {
+        bump: bump_queued_action,
+        id,
+        eta,
+        action: args.action.clone(),
+        queued_at: now_ts,
+    };
+    queued_action_data.serialize(&mut *queued_action.data.borrow_mut())?;
+    msg!("Queued admin action {} executable at {}", id, eta);
+
+    // write to base
+    base_data.admin_action_count = id
+        .checked_add(1)
+        .ok_or(CustomError::NumericalOverflow)?;
+    base_data.serialize(&mut *base.data.borrow_mut())?;
+
+    Ok(())
+}
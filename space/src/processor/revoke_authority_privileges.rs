@@ -10,7 +10,11 @@ use solana_program::{
 
 use crate::{
     instruction::RevokeAuthorityPrivilegesArgs,
-    state::Base,
+    processor::processor_utils::{apply_admin_action, assert_no_admin_delay},
+    state::{
+        AdminAction,
+        Base,
+    },
     validation_utils::{assert_keys_equal},
 };
 
@@ -32,15 +36,10 @@ pub fn process(
 
     // check that revoker is creator of base
     assert_keys_equal(base_data.authority, *revoker.key)?;
+    assert_no_admin_delay(&base_data)?;
 
-    // check that currently price exempt
-    if !base_data.authority_privileges {
-        msg!("Price exempt status already revoked!");
-        return Err(ProgramError::InvalidAccountData);
-    }
-
-    // write to base
-    base_data.authority_privileges = false;
+    // write to base, failing if no free neighborhoods remain
+    apply_admin_action(&mut base_data, &AdminAction::RevokeAuthorityPrivileges)?;
     base_data.serialize(&mut *base.data.borrow_mut())?;
 
     Ok(())
@@ -0,0 +1,49 @@
+use borsh::{BorshSerialize};
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    borsh::try_from_slice_unchecked,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    error::CustomError,
+    instruction::SetAdminDelayArgs,
+    state::Base,
+    validation_utils::{assert_keys_equal, assert_owned_by},
+};
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &SetAdminDelayArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let base = next_account_info(account_info_iter)?;
+    let authority = next_account_info(account_info_iter)?;
+
+    assert_owned_by(base, program_id)?;
+    let mut base_data: Base = try_from_slice_unchecked(&base.data.borrow_mut())?;
+
+    if !authority.is_signer {
+        msg!("Error: Missing signer");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // check that signer is authority of base
+    assert_keys_equal(base_data.authority, *authority.key)?;
+
+    // raising the delay only gives holders more warning, lowering it must wait out the current delay
+    if args.admin_delay < base_data.admin_delay {
+        msg!("Error: lowering the admin delay must be queued with QueueAdminAction");
+        return Err(CustomError::Timelocked.into());
+    }
+
+    // write to base
+    base_data.admin_delay = args.admin_delay;
+    base_data.serialize(&mut *base.data.borrow_mut())?;
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use borsh::{BorshSerialize};
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    borsh::try_from_slice_unchecked,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    instruction::SetNeighborhoodFeeArgs,
+    processor::processor_utils::get_max_neighborhood_fee_bps,
+    state::{
+        NEIGHBORHOOD_METADATA_SEED,
+        Base,
+        NeighborhoodMetadata,
+    },
+    validation_utils::{assert_keys_equal, assert_owned_by},
+};
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &SetNeighborhoodFeeArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let base = next_account_info(account_info_iter)?;
+    let neighborhood_metadata = next_account_info(account_info_iter)?;
+    let creator = next_account_info(account_info_iter)?;
+
+    if !creator.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    assert_owned_by(base, program_id)?;
+    let base_data: Base = try_from_slice_unchecked(&base.data.borrow())?;
+
+    // deserialize and verify neighborhood metadata
+    let mut neighborhood_metadata_data: NeighborhoodMetadata = try_from_slice_unchecked(&neighborhood_metadata.data.borrow_mut())?;
+    let seeds_neighborhood_metadata = &[
+        &base.key.to_bytes(),
+        NEIGHBORHOOD_METADATA_SEED,
+        &args.neighborhood_x.to_le_bytes(),
+        &args.neighborhood_y.to_le_bytes(),
+        &[neighborhood_metadata_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_neighborhood_metadata, program_id)?;
+    assert_keys_equal(key, *neighborhood_metadata.key)?;
+
+    // check that signer is creator of neighborhood
+    assert_keys_equal(neighborhood_metadata_data.creator, *creator.key)?;
+
+    // check override is within the bound set by the base authority
+    if let Some(fee_bps) = args.marketplace_fee_bps {
+        let max_fee_bps = get_max_neighborhood_fee_bps(&base_data);
+        if fee_bps > max_fee_bps {
+            msg!("Error: fee cannot exceed {} basis points", max_fee_bps);
+            return Err(ProgramError::InvalidInstructionData);
+        }
+    }
+
+    // write to neighborhood metadata
+    neighborhood_metadata_data.marketplace_fee_bps = args.marketplace_fee_bps;
+    neighborhood_metadata_data.serialize(&mut *neighborhood_metadata.data.borrow_mut())?;
+
+    Ok(())
+}
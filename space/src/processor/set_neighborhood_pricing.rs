@@ -0,0 +1,47 @@
+use borsh::{BorshSerialize};
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    borsh::try_from_slice_unchecked,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    instruction::SetNeighborhoodPricingArgs,
+    processor::processor_utils::{apply_admin_action, assert_no_admin_delay},
+    state::{
+        AdminAction,
+        Base,
+    },
+    validation_utils::{assert_keys_equal, assert_owned_by},
+};
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &SetNeighborhoodPricingArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let base = next_account_info(account_info_iter)?;
+    let authority = next_account_info(account_info_iter)?;
+
+    assert_owned_by(base, program_id)?;
+    let mut base_data: Base = try_from_slice_unchecked(&base.data.borrow_mut())?;
+
+    if !authority.is_signer {
+        msg!("Error: Missing signer");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // check that signer is authority of base
+    assert_keys_equal(base_data.authority, *authority.key)?;
+    assert_no_admin_delay(&base_data)?;
+
+    // write to base
+    apply_admin_action(&mut base_data, &AdminAction::SetNeighborhoodPricing { pricing: args.pricing })?;
+    base_data.serialize(&mut *base.data.borrow_mut())?;
+
+    Ok(())
+}
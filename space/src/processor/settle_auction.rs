@@ -0,0 +1,183 @@
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    borsh::try_from_slice_unchecked,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+    sysvar::{Sysvar, rent},
+};
+use spl_associated_token_account;
+use spl_token;
+
+use crate::{
+    error::CustomError,
+    instruction::SettleAuctionArgs,
+    processor::processor_utils::{assert_not_paused, close_account, create_ata_if_needed, create_fee_vault_if_needed, get_marketplace_fee, get_marketplace_fee_bps, get_neighborhood_xy, record_fee, transfer_lamports},
+    state::{
+        AUCTION_SEED,
+        AUCTION_VAULT_SEED,
+        NEIGHBORHOOD_METADATA_SEED,
+        Base,
+        Auction,
+        NeighborhoodMetadata,
+    },
+    validation_utils::{assert_is_ata, assert_keys_equal, assert_owned_by},
+};
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &SettleAuctionArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let base = next_account_info(account_info_iter)?;
+    let neighborhood_metadata = next_account_info(account_info_iter)?;
+    let fee_vault = next_account_info(account_info_iter)?;
+    let space_mint = next_account_info(account_info_iter)?;
+    let auction = next_account_info(account_info_iter)?;
+    let auction_vault = next_account_info(account_info_iter)?;
+    let seller = next_account_info(account_info_iter)?;
+    let winner = next_account_info(account_info_iter)?;
+    let winner_ata_space = next_account_info(account_info_iter)?;
+    let payer = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
+    let token_program = next_account_info(account_info_iter)?;
+    let associated_token_program = next_account_info(account_info_iter)?;
+    let rent_program = next_account_info(account_info_iter)?;
+
+    if !payer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // check programs
+    assert_keys_equal(system_program::id(), *system_program.key)?;
+    assert_keys_equal(spl_token::id(), *token_program.key)?;
+    assert_keys_equal(spl_associated_token_account::id(), *associated_token_program.key)?;
+    assert_keys_equal(rent::id(), *rent_program.key)?;
+
+    // deserialize base
+    assert_owned_by(base, program_id)?;
+    let base_data: Base = try_from_slice_unchecked(&base.data.borrow())?;
+    assert_not_paused(&base_data)?;
+
+    // deserialize and verify auction account
+    let auction_data: Auction = try_from_slice_unchecked(&auction.data.borrow())?;
+    let seeds_auction = &[
+        &base.key.to_bytes(),
+        AUCTION_SEED,
+        &args.space_x.to_le_bytes(),
+        &args.space_y.to_le_bytes(),
+        &[auction_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_auction, program_id)?;
+    assert_keys_equal(key, *auction.key)?;
+
+    // verify auction vault
+    let seeds_auction_vault = &[
+        &base.key.to_bytes(),
+        AUCTION_VAULT_SEED,
+        &args.space_x.to_le_bytes(),
+        &args.space_y.to_le_bytes(),
+        &[auction_data.vault_bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_auction_vault, program_id)?;
+    assert_keys_equal(key, *auction_vault.key)?;
+
+    if auction_data.mint != *space_mint.key {
+        msg!("Error: auction does not match mint account");
+        return Err(CustomError::MintMismatch.into());
+    }
+    assert_keys_equal(auction_data.seller, *seller.key)?;
+
+    // deserialize and verify neighborhood metadata
+    let neighborhood_metadata_data: NeighborhoodMetadata = try_from_slice_unchecked(&neighborhood_metadata.data.borrow())?;
+    let (neighborhood_x, neighborhood_y) = get_neighborhood_xy(args.space_x, args.space_y);
+    let seeds_neighborhood_metadata = &[
+        &base.key.to_bytes(),
+        NEIGHBORHOOD_METADATA_SEED,
+        &neighborhood_x.to_le_bytes(),
+        &neighborhood_y.to_le_bytes(),
+        &[neighborhood_metadata_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_neighborhood_metadata, program_id)?;
+    assert_keys_equal(key, *neighborhood_metadata.key)?;
+
+    // create fee vault if necessary
+    create_fee_vault_if_needed(program_id, base, fee_vault, payer, system_program, neighborhood_x, neighborhood_y)?;
+
+    // ensure auction has ended
+    let now_ts = Clock::get()?.unix_timestamp;
+    if now_ts < auction_data.end_timestamp {
+        msg!("Error: auction has not ended");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // space goes to the highest bidder, or back to the seller if nobody bid
+    if auction_data.highest_bid > 0 {
+        assert_keys_equal(auction_data.highest_bidder, *winner.key)?;
+    }
+    else {
+        assert_keys_equal(auction_data.seller, *winner.key)?;
+    }
+
+    // create winner ATA if necessary
+    create_ata_if_needed(payer, winner, space_mint, winner_ata_space, system_program, token_program, rent_program, associated_token_program)?;
+
+    // check ATAs
+    assert_is_ata(winner_ata_space, winner.key, space_mint.key)?;
+
+    // transfer NFT from auction vault to winner
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program.key,
+            auction_vault.key,
+            winner_ata_space.key,
+            auction.key,
+            &[],
+            1,
+        )?,
+        &[
+            token_program.clone(),
+            auction_vault.clone(),
+            winner_ata_space.clone(),
+            auction.clone(),
+        ],
+        &[seeds_auction],
+    )?;
+
+    // close auction vault, returning rent to seller
+    invoke_signed(
+        &spl_token::instruction::close_account(
+            token_program.key,
+            auction_vault.key,
+            seller.key,
+            auction.key,
+            &[],
+        )?,
+        &[
+            token_program.clone(),
+            auction_vault.clone(),
+            seller.clone(),
+            auction.clone(),
+        ],
+        &[seeds_auction],
+    )?;
+
+    // pay seller and neighborhood fee vault out of escrow
+    if auction_data.highest_bid > 0 {
+        let marketplace_fee = get_marketplace_fee(auction_data.highest_bid, get_marketplace_fee_bps(&base_data, &neighborhood_metadata_data))?;
+        transfer_lamports(auction, seller, auction_data.highest_bid - marketplace_fee)?;
+        transfer_lamports(auction, fee_vault, marketplace_fee)?;
+        record_fee(fee_vault, marketplace_fee)?;
+    }
+
+    // close auction account, returning rent to seller
+    close_account(auction, seller)?;
+
+    msg!("done!");
+    Ok(())
+}
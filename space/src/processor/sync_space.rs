@@ -0,0 +1,88 @@
+use borsh::{BorshSerialize};
+use solana_program::{
+    account_info::{AccountInfo, next_account_info},
+    borsh::try_from_slice_unchecked,
+    entrypoint::ProgramResult,
+    msg,
+    program_pack::Pack,
+    pubkey::Pubkey,
+};
+use spl_token;
+
+use crate::{
+    error::CustomError,
+    instruction::SyncSpaceArgs,
+    processor::processor_utils::{clear_listing, clear_listing_metadata},
+    state::{
+        LISTING_METADATA_SEED,
+        SPACE_METADATA_SEED,
+        ListingMetadata,
+        SpaceMetadata,
+    },
+    validation_utils::assert_keys_equal,
+};
+
+pub fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &SyncSpaceArgs,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let base = next_account_info(account_info_iter)?;
+    let space_metadata = next_account_info(account_info_iter)?;
+    let listing_metadata = next_account_info(account_info_iter)?;
+    let token_account = next_account_info(account_info_iter)?;
+
+    // deserialize and verify space metadata
+    let mut space_metadata_data: SpaceMetadata = try_from_slice_unchecked(&space_metadata.data.borrow_mut())?;
+    let seeds_space_metadata = &[
+        &base.key.to_bytes(),
+        SPACE_METADATA_SEED,
+        &args.space_x.to_le_bytes(),
+        &args.space_y.to_le_bytes(),
+        &[space_metadata_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_space_metadata, program_id)?;
+    assert_keys_equal(key, *space_metadata.key)?;
+
+    // deserialize and verify listing metadata
+    let mut listing_metadata_data: ListingMetadata = try_from_slice_unchecked(&listing_metadata.data.borrow_mut())?;
+    let seeds_listing_metadata = &[
+        &base.key.to_bytes(),
+        LISTING_METADATA_SEED,
+        &args.space_x.to_le_bytes(),
+        &args.space_y.to_le_bytes(),
+        &[listing_metadata_data.bump],
+    ];
+    let key = Pubkey::create_program_address(seeds_listing_metadata, program_id)?;
+    assert_keys_equal(key, *listing_metadata.key)?;
+    assert_keys_equal(listing_metadata_data.token_account, *token_account.key)?;
+
+    // the listing vault cannot lose the space
+    if listing_metadata_data.vault {
+        msg!("Error: space is held in the listing vault");
+        return Err(CustomError::InvalidListing.into());
+    }
+
+    // check the lister no longer holds the space in the listed token account
+    let still_held = token_account.owner == &spl_token::id()
+        && token_account.data_len() == spl_token::state::Account::LEN
+        && {
+            let token_account_data = spl_token::state::Account::unpack(&token_account.data.borrow())?;
+            token_account_data.owner == listing_metadata_data.seller
+                && token_account_data.mint == space_metadata_data.mint
+                && token_account_data.amount == 1
+        };
+    if still_held {
+        msg!("Error: lister still holds the space");
+        return Err(CustomError::InvalidListing.into());
+    }
+
+    // write to space and listing data
+    clear_listing(&mut space_metadata_data);
+    space_metadata_data.serialize(&mut *space_metadata.data.borrow_mut())?;
+    clear_listing_metadata(&mut listing_metadata_data);
+    listing_metadata_data.serialize(&mut *listing_metadata.data.borrow_mut())?;
+
+    Ok(())
+}
@@ -10,6 +10,7 @@ use solana_program::{
 
 use crate::{
     instruction::UpdateAuthorityArgs,
+    processor::processor_utils::assert_no_admin_delay,
     state::Base,
     validation_utils::{assert_keys_equal},
 };
@@ -26,17 +27,19 @@ pub fn process(
 
     let mut base_data: Base = try_from_slice_unchecked(&base.data.borrow_mut())?;
 
-    if !current_creator.is_signer {
+    if !current_creator.is_signer || !new_creator.is_signer {
         msg!("Error: Missing signer");
         return Err(ProgramError::MissingRequiredSignature);
     }
 
     // check that revoker is creator of base
     assert_keys_equal(base_data.authority, *current_creator.key)?;
+    assert_no_admin_delay(&base_data)?;
 
 
     // write to base
     base_data.authority = *new_creator.key;
+    base_data.pending_authority = None;
     base_data.serialize(&mut *base.data.borrow_mut())?;
 
     Ok(())
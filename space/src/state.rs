@@ -3,7 +3,9 @@ use solana_program::pubkey::Pubkey;
 use std::mem::size_of;
 
 pub const NEIGHBORHOOD_SIZE: usize = 200;
-pub const MARKETPLACE_FEE: f64 = 0.01;
+pub const DEFAULT_MARKETPLACE_FEE_BPS: u16 = 100;
+pub const DEFAULT_MAX_NEIGHBORHOOD_FEE_BPS: u16 = 500;
+pub const BASIS_POINTS: u16 = 10000;
 pub const EXTEND_TOKEN_MINT: &str = "PLACEHOLDER";
 pub const NEIGHBORHOOD_METADATA_SEED: &[u8] = b"neighborhood_metadata";
 pub const NEIGHBORHOOD_LIST_SEED: &[u8] = b"neighborhood_list";
@@ -11,17 +13,93 @@ pub const VOUCHER_MINT_SEED: &[u8] = b"voucher_mint";
 pub const VOUCHER_SINK_SEED: &[u8] = b"voucher_sink";
 pub const SPACE_METADATA_SEED: &[u8] = b"space_metadata";
 pub const SELL_DELEGATE_SEED: &[u8] = b"sell_delegate";
+pub const LISTING_METADATA_SEED: &[u8] = b"listing_metadata";
+pub const BID_SEED: &[u8] = b"bid";
+pub const NEIGHBORHOOD_BID_SEED: &[u8] = b"neighborhood_bid";
+pub const AUCTION_SEED: &[u8] = b"auction";
+pub const AUCTION_VAULT_SEED: &[u8] = b"auction_vault";
+pub const LISTING_VAULT_SEED: &[u8] = b"listing_vault";
+pub const SWAP_SEED: &[u8] = b"swap";
+pub const SWAP_VAULT_SEED: &[u8] = b"swap_vault";
+pub const BUNDLE_SEED: &[u8] = b"bundle";
+pub const FEE_VAULT_SEED: &[u8] = b"fee_vault";
+pub const ADMIN_ACTION_SEED: &[u8] = b"admin_action";
 
 pub const BASE_RESERVE: usize = 2048;
+pub const MAX_FREE_CREATORS: usize = 16;
+pub const INITIAL_FREE_NEIGHBORHOODS: u64 = 1;
+pub const DEFAULT_NEIGHBORHOOD_BASE_PRICE: u64 = 400000;
+pub const DEFAULT_NEIGHBORHOOD_RING_INCREMENT: u64 = 400000;
+pub const DEFAULT_NEIGHBORHOOD_FREE_RADIUS: u64 = 3;
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy)]
+pub struct NeighborhoodPricing {
+    pub base_price: u64,
+    // added for each ring past the free radius
+    pub ring_increment: u64,
+    // rings within this distance of the origin cost only the base price
+    pub free_radius: u64,
+    // rings past the cap cost the same as the cap ring
+    pub ring_cap: Option<u64>,
+}
+impl NeighborhoodPricing {
+    pub const LEN: usize = size_of::<u64>() + size_of::<u64>() + size_of::<u64>() + size_of::<u8>() + size_of::<u64>();
+}
+
+// neighborhood coordinates past this overflow space coordinates
+pub const MAX_NEIGHBORHOOD_COORDINATE: i64 = i64::MAX / NEIGHBORHOOD_SIZE as i64 - 1;
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy)]
+pub struct WorldBounds {
+    // inclusive neighborhood coordinates
+    pub min_x: i64,
+    pub min_y: i64,
+    pub max_x: i64,
+    pub max_y: i64,
+}
+impl WorldBounds {
+    pub const LEN: usize = size_of::<i64>() + size_of::<i64>() + size_of::<i64>() + size_of::<i64>();
+}
 #[repr(C)]
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
 pub struct Base {
     pub neighborhood_count: u64,
     pub authority: Pubkey,
+    // superseded by free_neighborhoods and free_creators, a base still carrying it with no free
+    // neighborhoods left is given INITIAL_FREE_NEIGHBORHOODS the next time a neighborhood is created
     pub authority_privileges: bool,
+    // None until set by the authority, in which case DEFAULT_MARKETPLACE_FEE_BPS applies
+    pub marketplace_fee_bps: Option<u16>,
+    // None until set by the authority, in which case DEFAULT_MAX_NEIGHBORHOOD_FEE_BPS applies
+    pub max_neighborhood_fee_bps: Option<u16>,
+    // share of the marketplace fee paid to a purchase's referrer
+    pub referral_fee_bps: u16,
+    // proposed authority, which takes over once it accepts
+    pub pending_authority: Option<Pubkey>,
+    // seconds an admin action must wait in the queue, zero lets the authority act directly
+    pub admin_delay: i64,
+    // number of admin actions ever queued, used to derive their addresses
+    pub admin_action_count: u64,
+    // stops trading, renting and coloring while set, mirrored in the color and rent programs
+    pub paused: bool,
+    // neighborhoods left that the authority or an allow-listed creator can create without burning EXTEND
+    pub free_neighborhoods: u64,
+    pub free_creators: Vec<Pubkey>,
+    // None until set by the authority, in which case the DEFAULT_NEIGHBORHOOD_* curve applies
+    pub neighborhood_pricing: Option<NeighborhoodPricing>,
+    // new neighborhoods other than the origin must share an edge with an existing one
+    pub require_adjacent: bool,
+    // None leaves the world unbounded up to MAX_NEIGHBORHOOD_COORDINATE
+    pub world_bounds: Option<WorldBounds>,
 }
 impl Base {
-    pub const LEN: usize = size_of::<u64>() + size_of::<Pubkey>() + size_of::<bool>();
+    pub const LEN: usize = size_of::<u64>() + size_of::<Pubkey>() + size_of::<bool>()
+        + size_of::<u8>() + size_of::<u16>() + size_of::<u8>() + size_of::<u16>() + size_of::<u16>()
+        + size_of::<u8>() + size_of::<Pubkey>() + size_of::<i64>() + size_of::<u64>()
+        + size_of::<bool>() + size_of::<u64>() + size_of::<u32>() + MAX_FREE_CREATORS * size_of::<Pubkey>()
+        + size_of::<u8>() + NeighborhoodPricing::LEN + size_of::<bool>() + size_of::<u8>() + WorldBounds::LEN;
 }
 
 pub const MAX_NEIGHBORHOODS: usize = 8;
@@ -44,10 +122,13 @@ pub struct NeighborhoodMetadata {
     pub candymachine_config: Pubkey,
     pub candymachine_account: Pubkey,
     pub neighborhood_name: [u8; 64],
+    // overrides the base marketplace fee, bounded by max_neighborhood_fee_bps
+    pub marketplace_fee_bps: Option<u16>,
 }
 
 impl NeighborhoodMetadata {
-    pub const LEN: usize = size_of::<u8>() + size_of::<Pubkey>() + size_of::<Pubkey>() + size_of::<Pubkey>() + 64*size_of::<u8>();
+    pub const LEN: usize = size_of::<u8>() + size_of::<Pubkey>() + size_of::<Pubkey>() + size_of::<Pubkey>() + 64*size_of::<u8>()
+        + size_of::<u8>() + size_of::<u16>();
 }
 
 pub const SPACE_METADATA_RESERVE: usize = 128;
@@ -59,9 +140,190 @@ pub struct SpaceMetadata {
     pub price: u64,
     pub space_x: i64,
     pub space_y: i64,
+    // dutch listings decline linearly from price to end_price between the two timestamps
+    pub end_price: u64,
+    pub start_timestamp: i64,
+    pub end_timestamp: i64,
+    // default pubkey means the listing is priced in SOL
+    pub payment_mint: Pubkey,
+    // zero means the listing never expires
+    pub expires_at: i64,
 }
 
 impl SpaceMetadata {
+    pub const LEN: usize =
+        size_of::<u8>() + size_of::<Pubkey>() + size_of::<u64>() + size_of::<i64>() + size_of::<i64>()
+        + size_of::<u64>() + size_of::<i64>() + size_of::<i64>() + size_of::<Pubkey>() + size_of::<i64>();
+}
+
+// SpaceMetadata accounts are full, so further listing terms live in their own account
+pub const LISTING_METADATA_RESERVE: usize = 256;
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct ListingMetadata {
+    pub bump: u8,
+    // default pubkey means anyone can buy
+    pub buyer: Pubkey,
+    // owner and token account that made the listing
+    pub seller: Pubkey,
+    pub token_account: Pubkey,
+    // space is held in the listing vault, owned by this account, rather than approved to the sell delegate
+    pub vault: bool,
+    pub vault_bump: u8,
+}
+
+impl ListingMetadata {
+    pub const LEN: usize = size_of::<u8>() + size_of::<Pubkey>() + size_of::<Pubkey>() + size_of::<Pubkey>()
+        + size_of::<bool>() + size_of::<u8>();
+}
+
+pub const BID_RESERVE: usize = 128;
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct Bid {
+    pub bump: u8,
+    pub bidder: Pubkey,
+    pub price: u64,
+    pub space_x: i64,
+    pub space_y: i64,
+}
+
+impl Bid {
     pub const LEN: usize =
         size_of::<u8>() + size_of::<Pubkey>() + size_of::<u64>() + size_of::<i64>() + size_of::<i64>();
-}
\ No newline at end of file
+}
+
+pub const NEIGHBORHOOD_BID_RESERVE: usize = 128;
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct NeighborhoodBid {
+    pub bump: u8,
+    pub bidder: Pubkey,
+    pub price: u64,
+    pub quantity: u64,
+    pub neighborhood_x: i64,
+    pub neighborhood_y: i64,
+}
+
+impl NeighborhoodBid {
+    pub const LEN: usize =
+        size_of::<u8>() + size_of::<Pubkey>() + size_of::<u64>() + size_of::<u64>() + size_of::<i64>() + size_of::<i64>();
+}
+
+pub const AUCTION_RESERVE: usize = 256;
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct Auction {
+    pub bump: u8,
+    pub vault_bump: u8,
+    pub seller: Pubkey,
+    pub mint: Pubkey,
+    pub space_x: i64,
+    pub space_y: i64,
+    pub reserve_price: u64,
+    pub min_increment: u64,
+    pub end_timestamp: i64,
+    pub highest_bid: u64,
+    pub highest_bidder: Pubkey,
+}
+
+impl Auction {
+    pub const LEN: usize = size_of::<u8>() + size_of::<u8>() + size_of::<Pubkey>() + size_of::<Pubkey>()
+        + size_of::<i64>() + size_of::<i64>() + size_of::<u64>() + size_of::<u64>() + size_of::<i64>()
+        + size_of::<u64>() + size_of::<Pubkey>();
+}
+
+pub const SWAP_RESERVE: usize = 256;
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct Swap {
+    pub bump: u8,
+    pub vault_bump: u8,
+    pub proposer: Pubkey,
+    pub offered_mint: Pubkey,
+    pub offered_x: i64,
+    pub offered_y: i64,
+    pub wanted_mint: Pubkey,
+    pub wanted_x: i64,
+    pub wanted_y: i64,
+    // lamports escrowed by the proposer for the acceptor
+    pub proposer_top_up: u64,
+    // lamports the acceptor pays the proposer
+    pub acceptor_top_up: u64,
+}
+
+impl Swap {
+    pub const LEN: usize = size_of::<u8>() + size_of::<u8>() + size_of::<Pubkey>() + size_of::<Pubkey>()
+        + size_of::<i64>() + size_of::<i64>() + size_of::<Pubkey>() + size_of::<i64>() + size_of::<i64>()
+        + size_of::<u64>() + size_of::<u64>();
+}
+
+// bundles move every space in one transaction, so keep them within account limits
+pub const MAX_BUNDLE_SPACES: i64 = 16;
+pub const BUNDLE_RESERVE: usize = 128;
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct Bundle {
+    pub bump: u8,
+    pub seller: Pubkey,
+    // inclusive corners of the rectangle
+    pub min_x: i64,
+    pub min_y: i64,
+    pub max_x: i64,
+    pub max_y: i64,
+    pub price: u64,
+}
+
+impl Bundle {
+    pub const LEN: usize = size_of::<u8>() + size_of::<Pubkey>() + size_of::<i64>() + size_of::<i64>()
+        + size_of::<i64>() + size_of::<i64>() + size_of::<u64>();
+}
+
+pub const FEE_VAULT_RESERVE: usize = 64;
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct FeeVault {
+    pub bump: u8,
+    pub neighborhood_x: i64,
+    pub neighborhood_y: i64,
+    // SOL marketplace fees paid into the vault since it was created
+    pub lifetime_fees: u64,
+}
+
+impl FeeVault {
+    pub const LEN: usize = size_of::<u8>() + size_of::<i64>() + size_of::<i64>() + size_of::<u64>();
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub enum AdminAction {
+    ProposeAuthority { new_authority: Pubkey },
+    RevokeAuthorityPrivileges,
+    SetMarketplaceFee { marketplace_fee_bps: u16, max_neighborhood_fee_bps: u16 },
+    SetReferralFee { referral_fee_bps: u16 },
+    SetAdminDelay { admin_delay: i64 },
+    SetFreeNeighborhoods { free_neighborhoods: u64, free_creators: Vec<Pubkey> },
+    SetNeighborhoodPricing { pricing: NeighborhoodPricing },
+    SetRequireAdjacent { require_adjacent: bool },
+    SetWorldBounds { world_bounds: Option<WorldBounds> },
+}
+
+pub const ADMIN_ACTION_RESERVE: usize = QueuedAdminAction::LEN;
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct QueuedAdminAction {
+    pub bump: u8,
+    pub id: u64,
+    // earliest timestamp the action can be executed
+    pub eta: i64,
+    pub action: AdminAction,
+    // timestamp the action was queued, so a later increase of admin_delay also applies to it
+    pub queued_at: i64,
+}
+
+impl QueuedAdminAction {
+    // sized for the largest action
+    pub const LEN: usize = size_of::<u8>() + size_of::<u64>() + size_of::<i64>()
+        + size_of::<u8>() + size_of::<u64>() + size_of::<u32>() + MAX_FREE_CREATORS * size_of::<Pubkey>()
+        + size_of::<i64>();
+}